@@ -119,7 +119,8 @@ fn single_tree(input: impl IntoIterator<Item = TokenTree>) -> Option<TokenTree>
     }
 }
 
-fn lit_impl(input: TokenStream) -> Result<TokenStream, TokenStream> {
+/// Parses `K` parenthesized arguments, looking through invisible groups.
+fn parse_args<const K: usize>(input: TokenStream) -> Result<[TokenStream; K], TokenStream> {
     let mut input = input.into_iter();
     let mut last_arg_span = Span::call_site();
     let mut get_arg = || -> Result<_, _> {
@@ -142,10 +143,58 @@ fn lit_impl(input: TokenStream) -> Result<TokenStream, TokenStream> {
         }
     };
 
-    let lit = get_arg()?;
-    let append = get_arg()?;
-    let zero = get_arg()?;
-    let one = get_arg()?;
+    let mut out = [(); K].map(|()| TokenStream::new());
+    for arg in &mut out {
+        *arg = get_arg()?;
+    }
+    Ok(out)
+}
+
+/// Generates the type for `num` from the type constructors `append`, `zero` and `one`.
+fn uint_tokens(num: &ibig::UBig, [append, zero, one]: [TokenStream; 3], span: Span) -> TokenStream {
+    let mut bits = (0..num.bit_len()).rev().map(move |i| num.bit(i));
+
+    let Some(true) = bits.next() else {
+        debug_assert!(
+            bits.all(|bit| !bit),
+            "Logic Error: First bit was zero/nonexistent but number was non-zero"
+        );
+        return zero;
+    };
+
+    let append_depth = bits.len();
+
+    // `Append<Append<...Append<Append<`
+    let output = iter::repeat_n(
+        append.extended(
+            Some(punct('<', span)), //
+        ),
+        append_depth,
+    );
+    // `Append<Append<...Append<Append<1`
+    let output = output.chain(
+        // First bit, `1`
+        Some(one.clone()),
+    );
+    // `Append<Append<...Append<Append<1, B1>, B2>...>, BN>`
+    let output = output.chain({
+        // `, B>`
+        let punct_bits = [zero, one].map(|c| {
+            Some(punct(',', span))
+                .into_iter()
+                .chain(c)
+                .chain(Some(punct('>', span)))
+                .collect::<TokenStream>()
+        });
+        // `, B1>, B2>, ...>, BN>`
+        bits.map(move |bit| punct_bits[usize::from(bit)].clone())
+    });
+
+    output.collect()
+}
+
+fn lit_impl(input: TokenStream) -> Result<TokenStream, TokenStream> {
+    let [lit, append, zero, one] = parse_args(input)?;
 
     let Some(TokenTree::Literal(lit)) = single_tree(lit.clone()) else {
         return Err(compile_error("Expected literal", lit));
@@ -154,58 +203,21 @@ fn lit_impl(input: TokenStream) -> Result<TokenStream, TokenStream> {
     let lit = lit.to_string().replace("_", "");
     let lit = lit.as_str();
 
-    let doit = |digits: &str, radix: u32| -> Result<TokenStream, Box<dyn std::error::Error>> {
-        let mut bits = {
-            let num = ibig::UBig::from_str_radix(digits, radix)?;
-            (0..num.bit_len()).rev().map(move |i| num.bit(i))
-        };
-
-        let Some(true) = bits.next() else {
-            debug_assert!(
-                bits.all(|bit| !bit),
-                "Logic Error: First bit was zero/nonexistent but number was non-zero"
-            );
-            return Ok(zero);
-        };
-
-        let append_depth = bits.len();
-
-        // `Append<Append<...Append<Append<`
-        let output = iter::repeat_n(
-            append.extended(
-                Some(punct('<', span)), //
-            ),
-            append_depth,
-        );
-        // `Append<Append<...Append<Append<1`
-        let output = output.chain(
-            // First bit, `1`
-            Some(one.clone()),
-        );
-        // `Append<Append<...Append<Append<1, B1>, B2>...>, BN>`
-        let output = output.chain({
-            // `, B>`
-            let punct_bits = [zero, one].map(|c| {
-                Some(punct(',', span))
-                    .into_iter()
-                    .chain(c)
-                    .chain(Some(punct('>', span)))
-                    .collect::<TokenStream>()
-            });
-            // `, B1>, B2>, ...>, BN>`
-            bits.map(move |bit| punct_bits[usize::from(bit)].clone())
-        });
-
-        Ok(output.collect())
+    let (digits, radix) = match lit.split_at_checked(2) {
+        Some(("0x", hex)) => (hex, 16),
+        Some(("0o", oct)) => (oct, 8),
+        Some(("0b", bin)) => (bin, 2),
+        _ => (lit, 10),
     };
+    let num = ibig::UBig::from_str_radix(digits, radix)
+        .map_err(|err| compile_error(&err.to_string(), span))?;
+    Ok(uint_tokens(&num, [append, zero, one], span))
+}
 
-    match lit.split_at_checked(2) {
-        Some(("0x", hex)) => doit(hex, 16),
-        Some(("0o", oct)) => doit(oct, 8),
-        Some(("0b", bin)) => doit(bin, 2),
-        _ => doit(lit, 10),
-    }
-    .map_err(|err| compile_error(&err.to_string(), span))
+fn count_impl(input: TokenStream) -> Result<TokenStream, TokenStream> {
+    let [items, append, zero, one] = parse_args(input)?;
+    let num = ibig::UBig::from(items.into_iter().count());
+    Ok(uint_tokens(&num, [append, zero, one], Span::call_site()))
 }
 
 #[doc(hidden)]
@@ -216,3 +228,13 @@ pub fn __lit(input: TokenStream) -> TokenStream {
         Err(out) => out,
     }
 }
+
+/// Like [`__lit`], but the first argument is a sequence of token trees, which are counted.
+#[doc(hidden)]
+#[proc_macro]
+pub fn __count(input: TokenStream) -> TokenStream {
+    match count_impl(input) {
+        Ok(out) => out,
+        Err(out) => out,
+    }
+}
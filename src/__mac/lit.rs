@@ -1 +1,36 @@
+use crate::{consts::ConstUsize, uint, uops};
+
 pub type _DirectAppend<H, P> = crate::uimpl::_U<H, P>;
+
+/// `H * 256 + B`, where `B` is a byte.
+pub type _PushByte<H, const B: usize> = uops::Add<uops::Shl<H, uint::lit!(8)>, ConstUsize<B>>;
+
+/// Builds a [`Uint`](crate::Uint) from the big-endian bytes of a `u64`. This is used to turn
+/// `usize` values computed in `const` into a `Uint`, since [`ConstUsize`] only supports small
+/// values.
+pub type FromBeBytes<
+    const B7: usize,
+    const B6: usize,
+    const B5: usize,
+    const B4: usize,
+    const B3: usize,
+    const B2: usize,
+    const B1: usize,
+    const B0: usize,
+> = uint::From<
+    _PushByte<
+        _PushByte<
+            _PushByte<
+                _PushByte<_PushByte<_PushByte<_PushByte<ConstUsize<B7>, B6>, B5>, B4>, B3>,
+                B2,
+            >,
+            B1,
+        >,
+        B0,
+    >,
+>;
+
+/// Returns byte `i` of `n`, counting from the least significant byte.
+pub const fn byte(n: usize, i: u32) -> usize {
+    ((n as u64 >> (i * 8)) & 0xFF) as usize
+}
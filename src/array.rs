@@ -11,6 +11,34 @@
 //! when interacting with them. These methods/impls have a line in their documentation stating that
 //! oversized arrays are unsupported.
 //!
+//! The comparison traits, [`Hash`](core::hash::Hash), [`Debug`](core::fmt::Debug) and iteration
+//! support oversized arrays. Since visiting every item would take more than [`usize::MAX`] steps,
+//! comparing, hashing and printing an oversized array only look at its first item. This gives the
+//! same result as visiting every item if the respective impl of the item type gives the same result
+//! for every instance, which is the case for zero-sized types whose impls do not depend on outside
+//! state. [`Debug`](core::fmt::Debug) prints them like a repeat expression `[x; len]`, with `len`
+//! given by [`ArrApi::length_str`].
+//!
+//! [`each_ref`](ArrApi::each_ref) and [`each_mut`](ArrApi::each_mut) can never support oversized
+//! arrays, since references are not zero-sized. They fail to compile for them.
+//! ```
+//! #![recursion_limit = "1024"]
+//! use genuint::{array::*, uint, uops, consts::PtrWidth};
+//! use std::hash::BuildHasher;
+//! type LargeSize = uint::From<uops::Shl<uint::lit!(1), PtrWidth>>;
+//! let arr = Arr::<_, LargeSize>::of(());
+//! assert!(arr <= Arr::of(()));
+//! assert_eq!(format!("{arr:?}"), format!("[(); {}]", 1u128 << usize::BITS));
+//!
+//! let state = std::hash::RandomState::new();
+//! assert_eq!(state.hash_one(arr), state.hash_one(Arr::<_, LargeSize>::of(())));
+//!
+//! let mut iter = arr.into_iter();
+//! assert_eq!(iter.size_hint(), (usize::MAX, None));
+//! iter.next();
+//! assert_eq!(iter.size_hint(), (usize::MAX, Some(usize::MAX)));
+//! ```
+//!
 //! [`Uint`]: crate::Uint
 //! [`Length`]: Array::Length
 
@@ -52,6 +80,31 @@ pub use crate::internals::array_types::*;
 /// This gives better lifetime inferrence for the item type. Some methods, such as
 /// [`Self::each_ref`] and the [`Index`](core::ops::Index) impl would not compile
 /// the way they are written without it.
+///
+/// Like a builtin array, this type can be indexed by `usize` and all range types and
+/// dereferences to `[T]`, so slice methods can be called on it directly. Both panic for
+/// [oversized arrays](crate::array#oversized-arrays).
+///
+/// Arithmetic and bitwise operators are applied element-wise, either between two arrays of the
+/// same length or between an array and a primitive scalar. See also [`Self::dot`].
+///
+/// Builtin arrays `[T; N]` convert to and from this type with [`From`] and compare with it
+/// whenever `N` is the same length.
+///
+/// # Examples
+/// ```
+/// use genuint::{array::*, small::*};
+/// let mut arr = Arr::<_, U5>::from_fn(|i| i);
+/// arr[0] = 10;
+/// assert_eq!(arr[1..3], [1, 2]);
+/// assert_eq!(arr.len(), 5);
+/// assert_eq!(arr.iter().max(), Some(&10));
+///
+/// let builtin: [usize; 5] = arr.into();
+/// assert_eq!(builtin, arr);
+/// assert!([0, 0, 0, 0, 0] < arr);
+/// assert_eq!(Arr::<_, U5>::from(builtin), arr);
+/// ```
 #[repr(transparent)]
 pub struct ArrApi<A: Array<Item = T>, T = <A as Array>::Item> {
     /// The array being wrapped.
@@ -109,6 +162,147 @@ impl<A> ArrFlatten<A> {
     }
 }
 
+/// Error returned by [`ArrApi::from_iter_exact`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FromIterExactError {
+    /// The iterator yielded fewer items than the length of the array.
+    ///
+    /// Contains the number of items that were yielded.
+    TooShort(usize),
+    /// The iterator yielded more items than the length of the array.
+    ///
+    /// This carries no count, since only the first surplus item is taken to detect this. Counting
+    /// the rest would consume an iterator that may be arbitrarily long or infinite.
+    TooLong,
+}
+impl core::fmt::Display for FromIterExactError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::TooShort(len) => write!(f, "iterator yielded only {len} items, which is too few"),
+            Self::TooLong => write!(f, "iterator yielded too many items"),
+        }
+    }
+}
+impl core::error::Error for FromIterExactError {}
+
+/// Error returned when an item does not fit into an [`ArrVecApi`].
+///
+/// Returned by [`ArrVecApi::try_extend`] and [`ArrVecApi::try_from_iter`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CapacityError<T> {
+    /// The item that was rejected.
+    pub item: T,
+    /// The number of items that were accepted before the vector was full.
+    pub accepted: usize,
+}
+impl<T> core::fmt::Display for CapacityError<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "insufficient capacity, vector was full after accepting {} items",
+            self.accepted
+        )
+    }
+}
+impl<T: core::fmt::Debug> core::error::Error for CapacityError<T> {}
+
+/// Error returned when a string does not fit into an [`ArrString`].
+///
+/// Returned by [`ArrString::try_push_str`] and the [`FromStr`](core::str::FromStr) and
+/// [`TryFrom<&str>`] impls of [`ArrString`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct StrCapacityError {
+    /// The length of the rejected string in bytes.
+    pub len: usize,
+    /// The number of bytes that were left in the [`ArrString`].
+    pub spare: usize,
+}
+impl core::fmt::Display for StrCapacityError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "insufficient capacity, string of {} bytes does not fit into {} spare bytes",
+            self.len, self.spare
+        )
+    }
+}
+impl core::error::Error for StrCapacityError {}
+
+/// A type-level function from [`Const`](type_const::Const)s of type `T` to `Const`s of type
+/// [`Output`](Self::Output).
+///
+/// This allows passing functions to `const` array constructors, which cannot call closures.
+/// See [`ArrApi::from_const_fn`] and [`ArrApi::map_const`].
+///
+/// # Examples
+/// ```
+/// use genuint::array::*;
+/// use type_const::Const;
+///
+/// struct Square;
+/// struct SquareOf<X>(X);
+/// impl<X: Const<Type = usize>> Const for SquareOf<X> {
+///     type Type = usize;
+///     const VALUE: usize = type_const::value_of::<X>() * type_const::value_of::<X>();
+/// }
+/// impl ConstMap<usize> for Square {
+///     type Output = usize;
+///     type Apply<X: Const<Type = usize>> = SquareOf<X>;
+/// }
+///
+/// const SQUARES: [usize; 4] = ArrApi::from_const_fn::<Square>().into_inner();
+/// assert_eq!(SQUARES, [0, 1, 4, 9]);
+/// ```
+pub trait ConstMap<T> {
+    /// The value type of the result.
+    type Output;
+    /// Applies the function to the value of `X`.
+    type Apply<X: type_const::Const<Type = T>>: type_const::Const<Type = Self::Output>;
+}
+
+/// A type-level strict weak ordering on `T`, used by [`ArrApi::sort_const`].
+///
+/// This is the `const` equivalent of a comparator closure.
+///
+/// # Examples
+/// ```
+/// use genuint::array::*;
+/// use type_const::{Const, value_of};
+///
+/// struct ByLen;
+/// struct ShorterThan<L, R>(L, R);
+/// impl<L, R> Const for ShorterThan<L, R>
+/// where
+///     L: Const<Type = &'static str>,
+///     R: Const<Type = &'static str>,
+/// {
+///     type Type = bool;
+///     const VALUE: bool = value_of::<L>().len() < value_of::<R>().len();
+/// }
+/// impl ConstLess<&'static str> for ByLen {
+///     type Less<L: Const<Type = &'static str>, R: Const<Type = &'static str>> = ShorterThan<L, R>;
+/// }
+///
+/// struct Keywords;
+/// impl Const for Keywords {
+///     type Type = [&'static str; 4];
+///     const VALUE: Self::Type = ["while", "if", "match", "fn"];
+/// }
+/// const SORTED: [&str; 4] = ArrApi::sort_const::<Keywords, ByLen>().into_inner();
+/// assert_eq!(SORTED, ["if", "fn", "while", "match"]);
+/// ```
+pub trait ConstLess<T> {
+    /// Whether the value of `L` is ordered before the value of `R`.
+    type Less<L: type_const::Const<Type = T>, R: type_const::Const<Type = T>>: type_const::Const<Type = bool>;
+}
+
+/// Primitive types that can be compared in `const`.
+///
+/// This trait is sealed and implemented for all primitive integers and [`char`]. It enables
+/// the `const` sorting and searching methods of [`ArrApi`], such as
+/// [`sort_unstable`](ArrApi::sort_unstable).
+pub trait PrimitiveOrd: internals::PrimitiveOrdSealed {}
+
 /// A wrapper for a [`MaybeUninit`](core::mem::MaybeUninit) array that acts as a [`Vec`]
 /// (with limited capacity), as well as a drop guard for the initialized items.
 ///
@@ -131,6 +325,33 @@ impl<A> ArrFlatten<A> {
 /// [Oversized arrays](crate::array#oversized-arrays) are never supported. Attempting to create
 /// such an [`ArrVecApi`] results in a panic at runtime. Note that this is not guaranteed and
 /// may be relaxed in the future.
+///
+/// # Examples
+/// Like [`Vec`], the vector dereferences to a slice and implements the standard traits
+/// based on its items.
+/// ```
+/// use genuint::{array::*, small::*};
+/// use std::collections::HashSet;
+///
+/// #[derive(Clone, PartialEq, Eq, Hash, Debug)]
+/// struct Path(ArrVec<u8, U8>);
+///
+/// let path = Path([1, 2, 3].into_iter().collect());
+/// let mut set = HashSet::new();
+/// set.insert(path.clone());
+/// assert!(set.contains(&path));
+///
+/// let mut vec = path.0;
+/// vec[0] = 4;
+/// vec.sort();
+/// assert_eq!(vec, [2, 3, 4]);
+/// assert_eq!(vec, ArrVec::<u8, U4>::from_iter([2, 3, 4]));
+/// assert!(vec > ArrVec::from_iter([2, 3]));
+/// for item in &mut vec {
+///     *item += 1;
+/// }
+/// assert_eq!(vec.first(), Some(&3));
+/// ```
 #[cfg_attr(not(doc), repr(transparent))]
 pub struct ArrVecApi<A: Array<Item = T>, T = <A as Array>::Item>(
     /// Encapsulates the drop impl to allow future changes
@@ -149,6 +370,23 @@ pub type ArrVec<T, N> = ArrVecApi<Arr<T, N>>;
 ///
 /// # Drop implementation
 /// See [`ArrVecApi#drop-implementation`]
+///
+/// # Examples
+/// ```
+/// use genuint::{array::*, small::*};
+///
+/// let mut deq = ArrDeq::<i32, U4>::from_iter([1, 2, 3, 4]);
+/// deq.pop_front();
+/// deq.push_back(5);
+/// assert_eq!(deq[0], 2);
+/// deq[3] *= 10;
+///
+/// let copy = deq.clone();
+/// assert_eq!(copy, [2, 3, 4, 50]);
+/// assert_eq!(copy, ArrVec::<i32, U5>::from_iter([2, 3, 4, 50]));
+/// assert_eq!(copy, deq);
+/// assert!(copy < ArrDeq::from_iter([3]));
+/// ```
 #[cfg_attr(not(doc), repr(transparent))]
 pub struct ArrDeqApi<A: Array<Item = T>, T = <A as Array>::Item>(
     /// Encapsulates the drop impl to allow future changes
@@ -158,6 +396,78 @@ pub struct ArrDeqApi<A: Array<Item = T>, T = <A as Array>::Item>(
 /// Alias for [`ArrDeqApi`] around [`Arr`].
 pub type ArrDeq<T, N> = ArrDeqApi<Arr<T, N>>;
 
+/// A UTF-8 string with a capacity of `N` bytes, backed by an [`ArrVec<u8, N>`].
+///
+/// This acts like [`String`], but never allocates. It dereferences to [`str`].
+/// Since the capacity is a [`Uint`](crate::Uint), it can be computed using [`uops`](crate::uops).
+///
+/// Use [`arr_str!`] to create a full string from a literal.
+///
+/// # Examples
+/// ```
+/// use genuint::{array::*, small::*, uint, uops};
+/// use core::fmt::Write;
+///
+/// const PREFIX: ArrString<U5> = arr_str!("node-");
+/// type Name = ArrString<uint::From<uops::Add<U5, U3>>>;
+///
+/// let mut name = Name::new();
+/// name.push_str(&PREFIX);
+/// write!(name, "{}", 42).unwrap();
+/// assert_eq!(name, "node-42");
+/// assert_eq!(name.to_uppercase(), "NODE-42");
+/// assert!(write!(name, "{}", 100).is_err());
+///
+/// let parsed: Name = "node-7".parse().unwrap();
+/// assert!(parsed > name);
+/// assert!(name < "node-8" && "node-1" < name);
+/// assert_eq!(
+///     "too long name".parse::<Name>(),
+///     Err(StrCapacityError { len: 13, spare: 8 })
+/// );
+/// ```
+pub struct ArrString<N: crate::Uint> {
+    /// # Safety
+    /// The items must be valid UTF-8
+    vec: ArrVec<u8, N>,
+}
+
+/// A cursor over a byte array, implementing [`Read`](std::io::Read),
+/// [`BufRead`](std::io::BufRead), [`Write`](std::io::Write) and [`Seek`](std::io::Seek).
+///
+/// This is the equivalent of [`std::io::Cursor`] around a `[u8; N]`. Writes never grow the
+/// array, so writing past its end only writes the part that fits.
+///
+/// For a growable buffer, [`ArrVecApi`] implements [`Write`](std::io::Write) and
+/// [`ArrDeqApi`] implements [`Read`](std::io::Read), [`BufRead`](std::io::BufRead) and
+/// [`Write`](std::io::Write) if the items are bytes.
+///
+/// # Examples
+/// ```
+/// use genuint::{array::*, small::*};
+/// use std::io::{Read, Seek, SeekFrom, Write};
+///
+/// let mut cursor = ArrCursor::new(Arr::<u8, U6>::of(0));
+/// cursor.write_all(&[0xAB, 4]).unwrap();
+/// cursor.write_all(b"ping").unwrap();
+/// assert!(cursor.write_all(b"!").is_err());
+///
+/// cursor.seek(SeekFrom::Start(1)).unwrap();
+/// let mut len = [0];
+/// cursor.read_exact(&mut len).unwrap();
+/// let mut payload = vec![0; len[0].into()];
+/// cursor.read_exact(&mut payload).unwrap();
+/// assert_eq!(payload, b"ping");
+/// assert_eq!(cursor.into_inner(), *b"\xAB\x04ping");
+/// ```
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ArrCursor<A: Array<Item = u8>> {
+    arr: ArrApi<A>,
+    pos: u64,
+}
+
 /// Helper macro that drops an [`ArrApi`], [`ArrVecApi`] or [`ArrDeqApi`], including in
 /// const contexts, by dropping each of its items.
 ///
@@ -196,11 +506,149 @@ macro_rules! __drop_items {
 }
 pub use __drop_items as drop_items;
 
+/// Concatenates any number of arrays with the same item type into one [`Arr`].
+///
+/// The length of the result is the sum of the lengths of the inputs. This works in `const`
+/// contexts.
+///
+/// This is equivalent to chaining [`ArrApi::concat`] and retyping the result, which
+/// avoids nested [`ArrConcat`] types. To split the result again, [`retype`](ArrApi::retype)
+/// it to an [`ArrConcat`] and use [`ArrApi::split_concat`].
+///
+/// # Examples
+/// ```
+/// use genuint::{array::*, small::*};
+/// const HEADER: [u8; 2] = [0xAB, 0xCD];
+/// const PACKET: Arr<u8, U7> = arr_concat![HEADER, [0; 4], [0xFF]];
+/// assert_eq!(PACKET, [0xAB, 0xCD, 0, 0, 0, 0, 0xFF]);
+///
+/// let single: Arr<u8, U2> = arr_concat![HEADER];
+/// assert_eq!(single, HEADER);
+/// ```
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __arr_concat {
+    [ $first:expr $(, $rest:expr)* $(,)? ] => {
+        $crate::array::ArrApi::new($first)
+            $(.concat($rest))*
+            .retype::<$crate::array::Arr<_, _>>()
+    };
+}
+pub use __arr_concat as arr_concat;
+
+/// Creates an [`Arr`], like the builtin array expression syntax.
+///
+/// - `arr![a, b, c]` creates an `Arr<T, uint::lit!(3)>` with the given items. The length is
+///   counted by the macro, so there is no limit on the number of items.
+/// - `arr![x; N]` creates an `Arr<T, N>` where every item is `x`, which has to be [`Copy`].
+///   `N` is either a [`Uint`](crate::Uint) type or a literal accepted by
+///   [`uint::lit!`](crate::uint::lit).
+///
+/// Both forms work in `const` contexts.
+///
+/// # Examples
+/// ```
+/// use genuint::{array::*, small::*};
+/// const PRIMES: Arr<u8, U4> = arr![2, 3, 5, 7];
+/// assert_eq!(PRIMES, [2, 3, 5, 7]);
+///
+/// const ZEROS: Arr<u8, U3> = arr![0; U3];
+/// assert_eq!(ZEROS, [0, 0, 0]);
+/// let ones = arr![1u16; 1000];
+/// assert_eq!(ones.len(), 1000);
+///
+/// let empty: Arr<String, U0> = arr![];
+/// assert!(empty.is_empty());
+/// ```
+///
+/// The length is not limited to the lengths supported by builtin arrays:
+/// ```
+/// use genuint::{array::*, uint};
+/// let arr = arr![
+///     0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+///     16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31,
+/// ];
+/// let _: &Arr<i32, uint::lit!(32)> = &arr;
+/// assert_eq!(arr[31], 31);
+/// ```
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __arr {
+    [ $x:expr; $n:literal ] => {
+        $crate::array::Arr::<_, $crate::uint::lit!($n)>::of($x)
+    };
+    [ $x:expr; $N:ty ] => {
+        $crate::array::Arr::<_, $N>::of($x)
+    };
+    [ $($x:expr),* $(,)? ] => {{
+        let mut __vec = $crate::array::ArrVecApi::<
+            $crate::array::Arr<
+                _,
+                $crate::__mac::proc::__count! {
+                    ($(($x))*)
+                    ($crate::__mac::lit::_DirectAppend)
+                    ($crate::small::U0)
+                    ($crate::small::U1)
+                },
+            >,
+        >::new();
+        $(__vec.push($x);)*
+        __vec.assert_full()
+    }};
+}
+pub use __arr as arr;
+
+/// Creates a full [`ArrString`] from a string literal.
+///
+/// The capacity is the length of the literal in bytes, i.e. [`str::len`], as a
+/// [`Uint`](crate::Uint). It is not limited to the lengths that
+/// [`ConstUsize`](crate::consts::ConstUsize) supports. This works in `const` contexts.
+///
+/// # Examples
+/// ```
+/// use genuint::{array::*, small::*};
+/// const GREETING: ArrString<U6> = arr_str!("hello\n");
+/// assert_eq!(GREETING, "hello\n");
+///
+/// let unicode = arr_str!("√x");
+/// assert_eq!(unicode.capacity(), 4);
+/// assert!(unicode.is_full());
+///
+/// let raw = arr_str!(r#"\d+"#);
+/// assert_eq!(raw.capacity(), 3);
+/// ```
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __arr_str {
+    ($s:literal) => {{
+        let mut __string = $crate::array::ArrString::<
+            $crate::__mac::lit::FromBeBytes<
+                { $crate::__mac::lit::byte($s.len(), 7) },
+                { $crate::__mac::lit::byte($s.len(), 6) },
+                { $crate::__mac::lit::byte($s.len(), 5) },
+                { $crate::__mac::lit::byte($s.len(), 4) },
+                { $crate::__mac::lit::byte($s.len(), 3) },
+                { $crate::__mac::lit::byte($s.len(), 2) },
+                { $crate::__mac::lit::byte($s.len(), 1) },
+                { $crate::__mac::lit::byte($s.len(), 0) },
+            >,
+        >::new();
+        __string.push_str($s);
+        __string
+    }};
+}
+
+pub use __arr_str as arr_str;
+
 pub(crate) mod container;
 pub(crate) mod helper;
 
 mod arr_deq;
+mod arr_string;
 mod arr_vec;
 mod impls;
+#[cfg(feature = "std")]
+mod io_impl;
 
 pub mod arr_api;
+pub mod matrix;
@@ -18,6 +18,24 @@
 //! [`CondResult`], even for references, since [`CondResult`] does not need extra space for a
 //! discriminant, so there is no niche optimization benifit from using an option (or ZST error
 //! type).
+//!
+//! # `{box, rc, arc}_{uninit, from_fn, of, default}`
+//! Functions that create arrays directly on the heap, without placing them on the stack first.
+//!
+//! Unlike `Box::new(ArrApi::from_fn(f))`, [`box_from_fn`] writes each item straight into the
+//! allocation, so arrays that are too large for the stack can be created.
+//!
+//! ```
+//! # #[cfg(feature = "alloc")] {
+//! use genuint::{array::*, uint};
+//! type Table = Arr<u32, uint::lit!(4_000_000)>; // 16 MB
+//! let table: Box<Table> = arr_api::box_from_fn(|i| i as u32 * 3);
+//! assert_eq!(table[1_000_000], 3_000_000);
+//!
+//! let zeros = arr_api::arc_default::<Table>();
+//! assert!(zeros.iter().all(|&x| x == 0));
+//! # }
+//! ```
 
 use crate::{
     array::{Array, helper::*},
@@ -124,6 +142,13 @@ decl_ptr![
         retype: (retype_box, try_retype_box),
         unsize: unsize_box,
         try_from_slice: (try_from_boxed_slice, FromSliceResult),
+        heap: (
+            box_uninit,
+            box_from_fn,
+            box_of,
+            box_default,
+            get_mut = |b| &mut **b
+        ),
     },
 ];
 decl_ptr![
@@ -142,6 +167,13 @@ decl_ptr![
         retype: (retype_rc, try_retype_rc),
         unsize: unsize_rc,
         try_from_slice: (try_from_rc_slice, FromSliceResult),
+        heap: (
+            rc_uninit,
+            rc_from_fn,
+            rc_of,
+            rc_default,
+            get_mut = |r| alloc::rc::Rc::get_mut(r).unwrap()
+        ),
     },
 ];
 decl_ptr![
@@ -160,6 +192,13 @@ decl_ptr![
         retype: (retype_arc, try_retype_arc),
         unsize: unsize_arc,
         try_from_slice: (try_from_arc_slice, FromSliceResult),
+        heap: (
+            arc_uninit,
+            arc_from_fn,
+            arc_of,
+            arc_default,
+            get_mut = |r| alloc::sync::Arc::get_mut(r).unwrap()
+        ),
     },
 ];
 
@@ -173,7 +212,6 @@ macro_rules! for_each_ptr {
         Owned! { fn $fn, $cb }
     };
 }
-pub(crate) use for_each_ptr;
 
 macro_rules! decl_retype {
     ($ty:ident { $($mods:tt)* } ($retype:ident, $try_retype:ident)) => {
@@ -302,3 +340,115 @@ macro_rules! decl_from_slice {
     };
 }
 for_each_ptr!(try_from_slice, decl_from_slice);
+
+/// Initializes the items of `dst` by calling `f` with each index.
+///
+/// The items that were already initialized are dropped if `f` panics.
+#[cfg(feature = "alloc")]
+#[track_caller]
+fn fill_uninit<A: Array>(dst: &mut core::mem::MaybeUninit<A>, mut f: impl FnMut(usize) -> A::Item) {
+    struct Guard<'a, T> {
+        items: &'a mut [core::mem::MaybeUninit<T>],
+        init: usize,
+    }
+    impl<T> Drop for Guard<'_, T> {
+        fn drop(&mut self) {
+            let init = core::ptr::slice_from_raw_parts_mut(self.items.as_mut_ptr(), self.init);
+            // SAFETY: The first `init` items are initialized
+            unsafe { core::ptr::drop_in_place(init as *mut [T]) }
+        }
+    }
+
+    let len = arr_len::<A>();
+    let mut guard = Guard {
+        items: unsize_mut(dst),
+        init: 0,
+    };
+    while guard.init < len {
+        guard.items[guard.init].write(f(guard.init));
+        guard.init += 1;
+    }
+    core::mem::forget(guard);
+}
+
+macro_rules! decl_heap {
+    (
+        $ty:ident { $($mods:tt)* }
+        ($uninit:ident, $from_fn:ident, $of:ident, $default:ident, get_mut = |$get_mut_par:pat_param| $get_mut:expr)
+    ) => {
+        #[doc = core::concat!(
+            "Allocates an uninitialized ",
+            $ty!(docname, "MaybeUninit<impl Array>"),
+            ".",
+        )]
+        ///
+        /// The array is never placed on the stack.
+        $($mods)* fn $uninit<A: Array>() -> $ty!(typ, core::mem::MaybeUninit<A>) {
+            arr_impl_ubcheck::<A>();
+            <$ty!(typ, A)>::new_uninit()
+        }
+
+        #[doc = core::concat!(
+            "Creates ",
+            $ty!(docname, "impl Array"),
+            " by calling `f` with each index, like [`ArrApi::from_fn`](crate::array::ArrApi::from_fn).",
+        )]
+        ///
+        /// The items are written directly into the allocation, so the array is never placed on
+        /// the stack. This makes it possible to create arrays that are too large for the stack.
+        ///
+        /// # Panics
+        /// If `Length > usize::MAX`. The generating function is not called in this case.
+        #[track_caller]
+        $($mods)* fn $from_fn<A: Array>(f: impl FnMut(usize) -> A::Item) -> $ty!(typ, A) {
+            let _ = arr_len::<A>();
+            let mut uninit = $uninit::<A>();
+            fill_uninit::<A>(
+                {
+                    let $get_mut_par = &mut uninit;
+                    $get_mut
+                },
+                f,
+            );
+            // SAFETY: `fill_uninit` initialized all items
+            unsafe { uninit.assume_init() }
+        }
+
+        #[doc = core::concat!(
+            "Creates ",
+            $ty!(docname, "impl Array"),
+            " with every item set to a clone of `item`.",
+        )]
+        ///
+        #[doc = core::concat!("See [`", core::stringify!($from_fn), "`].")]
+        ///
+        /// # Panics
+        /// If `Length > usize::MAX`.
+        #[track_caller]
+        $($mods)* fn $of<A: Array>(item: A::Item) -> $ty!(typ, A)
+        where
+            A::Item: Clone,
+        {
+            $from_fn(|_| item.clone())
+        }
+
+        #[doc = core::concat!(
+            "Creates ",
+            $ty!(docname, "impl Array"),
+            " with every item set to [`Default::default`].",
+        )]
+        ///
+        #[doc = core::concat!("See [`", core::stringify!($from_fn), "`].")]
+        ///
+        /// # Panics
+        /// If `Length > usize::MAX`.
+        #[track_caller]
+        $($mods)* fn $default<A: Array>() -> $ty!(typ, A)
+        where
+            A::Item: Default,
+        {
+            $from_fn(|_| Default::default())
+        }
+    };
+}
+for_each_ptr!(heap, decl_heap);
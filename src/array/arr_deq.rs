@@ -1,8 +1,17 @@
-use core::{marker::PhantomData, mem::MaybeUninit, ptr::NonNull};
+use core::{
+    marker::PhantomData,
+    mem::MaybeUninit,
+    ops::{Range, RangeBounds},
+    ptr::{self, NonNull},
+};
 
 use crate::{const_fmt, utils};
 
-use super::{ArrApi, ArrDeqApi, Array, helper::*};
+use super::{
+    ArrApi, ArrDeqApi, Array,
+    helper::*,
+    impls::iter::{IterDeq, IterMutDeq},
+};
 
 /// Wraps the drop impl so it isn't exposed as a trait bound
 // NOTE: Mutable access to fields and construction of this struct requires a safety
@@ -423,6 +432,83 @@ impl<A: Array<Item = T>, T> ArrDeqApi<A> {
         Ok(())
     }
 
+    /// Like [`push_back`](Self::push_back), but evicts and returns the front item if the deque
+    /// is full.
+    ///
+    /// This keeps the deque as a window over the last `capacity` pushed items. If the capacity
+    /// is zero, `item` is returned back.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::array::*;
+    ///
+    /// let mut samples = ArrDeqApi::<[_; 3]>::new();
+    /// assert_eq!(samples.push_back_overwrite(1), None);
+    /// samples.push_back_overwrite(2);
+    /// samples.push_back_overwrite(3);
+    /// assert_eq!(samples.push_back_overwrite(4), Some(1));
+    /// assert_eq!(samples, [2, 3, 4]);
+    /// ```
+    pub const fn push_back_overwrite(&mut self, item: T) -> Option<T> {
+        if self.capacity() == 0 {
+            return Some(item);
+        }
+        let evicted = if self.is_full() {
+            self.pop_front()
+        } else {
+            None
+        };
+        self.push_back(item);
+        evicted
+    }
+
+    /// Like [`push_front`](Self::push_front), but evicts and returns the back item if the deque
+    /// is full.
+    ///
+    /// If the capacity is zero, `item` is returned back.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::array::*;
+    ///
+    /// let mut deq = ArrDeqApi::new_full([1, 2, 3]);
+    /// assert_eq!(deq.push_front_overwrite(0), Some(3));
+    /// assert_eq!(deq, [0, 1, 2]);
+    /// ```
+    pub const fn push_front_overwrite(&mut self, item: T) -> Option<T> {
+        if self.capacity() == 0 {
+            return Some(item);
+        }
+        let evicted = if self.is_full() {
+            self.pop_back()
+        } else {
+            None
+        };
+        self.push_front(item);
+        evicted
+    }
+
+    /// Pushes all items of `iter` to the back with
+    /// [`push_back_overwrite`](Self::push_back_overwrite), dropping evicted items.
+    ///
+    /// Afterwards, the deque holds the last `capacity` items of the deque followed by `iter`.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::{array::*, small::*};
+    ///
+    /// let mut window = ArrDeq::<i32, U4>::new();
+    /// window.extend_overwrite(1..=3);
+    /// assert_eq!(window, [1, 2, 3]);
+    /// window.extend_overwrite(4..=10);
+    /// assert_eq!(window, [7, 8, 9, 10]);
+    /// assert_eq!(window.iter().sum::<i32>(), 34);
+    /// ```
+    pub fn extend_overwrite<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        iter.into_iter()
+            .for_each(|item| drop(self.push_back_overwrite(item)));
+    }
+
     /// Returns a reference to the elements as a pair of slices.
     ///
     /// If this deque is contiguous, then the right slice will be empty.
@@ -475,25 +561,6 @@ impl<A: Array<Item = T>, T> ArrDeqApi<A> {
     /// ```
     #[inline]
     pub const fn make_contiguous(&mut self) -> &mut [T] {
-        /// This is way less performant than <[T]>::rotate_left, which is not const.
-        const fn rotate_left<T>(slice: &mut [T], dist: usize) {
-            const fn reverse<T>(slice: &mut [T]) {
-                let mut i = 0;
-                while i < slice.len() / 2 {
-                    slice.swap(i, slice.len() - i - 1);
-                    i += 1;
-                }
-            }
-            let (lhs, rhs) = slice.split_at_mut(dist);
-            // EFGHIJKLMN^ABCD
-            reverse(lhs);
-            // NMLKJIHGFE^ABCD
-            reverse(rhs);
-            // NMLKJIHGFE^DBCA
-            reverse(slice);
-            // ABCDEFGHIJ^KLMN
-        }
-
         // SAFETY: Left rotation by `head`, i.e. right rotation by cap - head, which means:
         // - Start of the first range: `head`
         //   -> `0`
@@ -514,7 +581,7 @@ impl<A: Array<Item = T>, T> ArrDeqApi<A> {
         // Since the second range is empty in case 2, we always get that the valid elements are
         // contiguous in `arr[0..len]`. Hence we can set head to 0.
         let repr = unsafe { self.as_mut_repr() };
-        rotate_left(repr.arr.as_mut_slice(), repr.head);
+        rotate_slice_left(repr.arr.as_mut_slice(), repr.head);
         repr.head = 0;
 
         self.as_mut_slices().0
@@ -604,4 +671,749 @@ impl<A: Array<Item = T>, T> ArrDeqApi<A> {
     }
 }
 
+// Access methods
+impl<A: Array<Item = T>, T> ArrDeqApi<A> {
+    /// Equivalent of [`VecDeque::get`](std::collections::VecDeque::get).
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::array::*;
+    ///
+    /// let mut deq = ArrDeqApi::new_full([1, 2, 3]);
+    /// deq.pop_front();
+    /// deq.push_back(4);
+    /// assert_eq!(deq.get(2), Some(&4));
+    /// assert_eq!(deq.get(3), None);
+    /// ```
+    pub const fn get(&self, index: usize) -> Option<&T> {
+        let (lhs, rhs) = self.as_slices();
+        if index < lhs.len() {
+            Some(&lhs[index])
+        } else if index - lhs.len() < rhs.len() {
+            Some(&rhs[index - lhs.len()])
+        } else {
+            None
+        }
+    }
+
+    /// Equivalent of [`VecDeque::get_mut`](std::collections::VecDeque::get_mut).
+    pub const fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        let (lhs, rhs) = self.as_mut_slices();
+        if index < lhs.len() {
+            Some(&mut lhs[index])
+        } else if index - lhs.len() < rhs.len() {
+            Some(&mut rhs[index - lhs.len()])
+        } else {
+            None
+        }
+    }
+
+    /// Equivalent of [`VecDeque::front`](std::collections::VecDeque::front).
+    pub const fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    /// Equivalent of [`VecDeque::front_mut`](std::collections::VecDeque::front_mut).
+    pub const fn front_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(0)
+    }
+
+    /// Equivalent of [`VecDeque::back`](std::collections::VecDeque::back).
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::array::*;
+    ///
+    /// const LAST: Option<i32> = {
+    ///     let mut deq = ArrDeqApi::<[i32; 3]>::new();
+    ///     deq.push_front(1);
+    ///     deq.push_front(2);
+    ///     let last = match deq.back() {
+    ///         Some(&last) => Some(last),
+    ///         None => None,
+    ///     };
+    ///     drop_items!(deq);
+    ///     last
+    /// };
+    /// assert_eq!(LAST, Some(1));
+    /// ```
+    pub const fn back(&self) -> Option<&T> {
+        match self.len().checked_sub(1) {
+            Some(last) => self.get(last),
+            None => None,
+        }
+    }
+
+    /// Equivalent of [`VecDeque::back_mut`](std::collections::VecDeque::back_mut).
+    pub const fn back_mut(&mut self) -> Option<&mut T> {
+        match self.len().checked_sub(1) {
+            Some(last) => self.get_mut(last),
+            None => None,
+        }
+    }
+
+    /// Equivalent of [`VecDeque::swap`](std::collections::VecDeque::swap).
+    ///
+    /// # Panics
+    /// If either index is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::array::*;
+    ///
+    /// let mut deq = ArrDeqApi::new_full([1, 2, 3]);
+    /// deq.pop_front();
+    /// deq.push_back(4);
+    /// deq.swap(0, 2);
+    /// assert_eq!(deq, [4, 3, 2]);
+    /// ```
+    #[track_caller]
+    pub const fn swap(&mut self, i: usize, j: usize) {
+        let len = self.len();
+        if i >= len || j >= len {
+            const_fmt::fmt![
+                "swap indices (are ",
+                i,
+                " and ",
+                j,
+                ") should be < len (is ",
+                len,
+                ")"
+            ]
+            .panic()
+        }
+        // SAFETY: Only valid items are swapped with each other
+        let repr = unsafe { self.as_mut_repr() };
+        let (i, j) = (repr.phys_idx_of(i), repr.phys_idx_of(j));
+        repr.arr.as_mut_slice().swap(i, j);
+    }
+
+    /// Equivalent of [`VecDeque::iter`](std::collections::VecDeque::iter).
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::array::*;
+    ///
+    /// // A sliding window over the last three values
+    /// let mut history = ArrDeqApi::<[i32; 3]>::new();
+    /// for value in [5, 1, 4, 2, 8] {
+    ///     history.push_back_overwrite(value);
+    /// }
+    /// assert_eq!(history.iter().sum::<i32>(), 14);
+    /// assert_eq!(history.iter().rev().next(), Some(&8));
+    /// ```
+    pub fn iter(&self) -> IterDeq<'_, T> {
+        self.into_iter()
+    }
+
+    /// Equivalent of [`VecDeque::iter_mut`](std::collections::VecDeque::iter_mut).
+    pub fn iter_mut(&mut self) -> IterMutDeq<'_, T> {
+        self.into_iter()
+    }
+
+    /// Equivalent of [`VecDeque::range`](std::collections::VecDeque::range).
+    ///
+    /// # Panics
+    /// If the start of the range is greater than its end or if the end is greater than the
+    /// length of the deque.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::array::*;
+    ///
+    /// let mut deq = ArrDeqApi::new_full([1, 2, 3, 4]);
+    /// deq.pop_front();
+    /// deq.push_back(5);
+    /// assert!(deq.range(1..3).eq(&[3, 4]));
+    /// assert!(deq.range(2..).eq(&[4, 5]));
+    /// ```
+    #[track_caller]
+    pub fn range(&self, range: impl RangeBounds<usize>) -> IterDeq<'_, T> {
+        let (lhs, rhs) = self.as_slices();
+        let (lhs_range, rhs_range) = split_range(range, lhs.len(), self.len());
+        IterDeq {
+            items: lhs[lhs_range].iter().chain(&rhs[rhs_range]),
+        }
+    }
+
+    /// Equivalent of [`VecDeque::range_mut`](std::collections::VecDeque::range_mut).
+    ///
+    /// # Panics
+    /// Like [`range`](Self::range).
+    #[track_caller]
+    pub fn range_mut(&mut self, range: impl RangeBounds<usize>) -> IterMutDeq<'_, T> {
+        let len = self.len();
+        let (lhs, rhs) = self.as_mut_slices();
+        let (lhs_range, rhs_range) = split_range(range, lhs.len(), len);
+        IterMutDeq {
+            items: lhs[lhs_range].iter_mut().chain(&mut rhs[rhs_range]),
+        }
+    }
+
+    /// Equivalent of [`VecDeque::contains`](std::collections::VecDeque::contains).
+    pub fn contains(&self, item: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        let (lhs, rhs) = self.as_slices();
+        lhs.contains(item) || rhs.contains(item)
+    }
+
+    /// Equivalent of [`VecDeque::binary_search`](std::collections::VecDeque::binary_search).
+    ///
+    /// # Errors
+    /// If the item is not found, the index where it could be inserted while maintaining the
+    /// sorted order is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::array::*;
+    ///
+    /// let mut deq = ArrDeqApi::new_full([0, 1, 3, 5, 8]);
+    /// deq.pop_front();
+    /// deq.push_back(13);
+    /// assert_eq!(deq.as_slices().1, [13]);
+    /// assert_eq!(deq.binary_search(&5), Ok(2));
+    /// assert_eq!(deq.binary_search(&13), Ok(4));
+    /// assert_eq!(deq.binary_search(&10), Err(4));
+    /// assert_eq!(deq.binary_search(&20), Err(5));
+    /// ```
+    pub fn binary_search(&self, item: &T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        self.binary_search_by(|probe| probe.cmp(item))
+    }
+
+    /// Equivalent of [`VecDeque::binary_search_by`](std::collections::VecDeque::binary_search_by).
+    ///
+    /// # Errors
+    /// Like [`binary_search`](Self::binary_search).
+    pub fn binary_search_by<F>(&self, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> core::cmp::Ordering,
+    {
+        let (lhs, rhs) = self.as_slices();
+        let offset = lhs.len() + 1;
+        match rhs.first().map(&mut f) {
+            Some(core::cmp::Ordering::Equal) => Ok(lhs.len()),
+            Some(core::cmp::Ordering::Less) => rhs[1..]
+                .binary_search_by(f)
+                .map(|idx| idx + offset)
+                .map_err(|idx| idx + offset),
+            _ => lhs.binary_search_by(f),
+        }
+    }
+
+    /// Equivalent of
+    /// [`VecDeque::binary_search_by_key`](std::collections::VecDeque::binary_search_by_key).
+    ///
+    /// # Errors
+    /// Like [`binary_search`](Self::binary_search).
+    pub fn binary_search_by_key<B, F>(&self, key: &B, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> B,
+        B: Ord,
+    {
+        self.binary_search_by(|probe| f(probe).cmp(key))
+    }
+
+    /// Equivalent of [`VecDeque::partition_point`](std::collections::VecDeque::partition_point).
+    pub fn partition_point<P>(&self, mut pred: P) -> usize
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let (lhs, rhs) = self.as_slices();
+        if rhs.first().is_some_and(&mut pred) {
+            rhs[1..].partition_point(pred) + lhs.len() + 1
+        } else {
+            lhs.partition_point(pred)
+        }
+    }
+}
+
+// `VecDeque` editing methods
+impl<A: Array<Item = T>, T> ArrDeqApi<A> {
+    /// Equivalent of [`VecDeque::insert`](std::collections::VecDeque::insert).
+    ///
+    /// Whichever side of `index` is shorter is moved to make room.
+    ///
+    /// # Panics
+    /// If `index > len` or if the deque is full.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::array::*;
+    ///
+    /// let mut deq = ArrDeqApi::<[_; 5]>::new();
+    /// deq.push_back(1);
+    /// deq.push_back(4);
+    /// deq.push_front(0);
+    /// deq.insert(2, 2);
+    /// deq.insert(3, 3);
+    /// assert_eq!(deq, [0, 1, 2, 3, 4]);
+    /// ```
+    #[track_caller]
+    pub const fn insert(&mut self, index: usize, item: T) {
+        let len = self.len();
+        if index > len {
+            const_fmt::fmt![
+                "insertion index (is ",
+                index,
+                ") should be <= len (is ",
+                len,
+                ")"
+            ]
+            .panic()
+        }
+        if self.is_full() {
+            panic!("Call to `insert` on full `ArrDeqApi`")
+        }
+
+        // SAFETY: See below
+        let repr = unsafe { self.as_mut_repr() };
+        if index < len - index {
+            // Move the items before `index` one place to the front
+            repr.head = repr.phys_idx_before_head(1);
+            let mut i = 0;
+            while i < index {
+                // SAFETY: `len < cap`. The place at `i` was freed by the previous move or by
+                // moving the head.
+                unsafe { repr.move_item(i + 1, i) };
+                i += 1;
+            }
+        } else {
+            // Move the items after `index` one place to the back
+            let mut i = len;
+            while i > index {
+                // SAFETY: `len < cap`. The place at `i` is spare or was freed by the previous
+                // move.
+                unsafe { repr.move_item(i - 1, i) };
+                i -= 1;
+            }
+        }
+        let idx = repr.phys_idx_of(index);
+        repr.arr.as_mut_slice()[idx].write(item);
+        // No overflow because the deque was not full
+        repr.len += 1;
+    }
+
+    /// Equivalent of [`VecDeque::remove`](std::collections::VecDeque::remove).
+    ///
+    /// Whichever side of `index` is shorter is moved to close the gap.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::array::*;
+    ///
+    /// let mut deq = ArrDeqApi::new_full([1, 2, 3, 4]);
+    /// assert_eq!(deq.remove(1), Some(2));
+    /// assert_eq!(deq.remove(2), Some(4));
+    /// assert_eq!(deq.remove(2), None);
+    /// assert_eq!(deq, [1, 3]);
+    /// ```
+    pub const fn remove(&mut self, index: usize) -> Option<T> {
+        let len = self.len();
+        if index >= len {
+            return None;
+        }
+
+        // SAFETY: See below
+        let repr = unsafe { self.as_mut_repr() };
+        // SAFETY: The item at `index` is valid. Its place is filled or given up below.
+        let item = unsafe { repr.phys_read(repr.phys_idx_of(index)) };
+        if index < len - 1 - index {
+            // Move the items before `index` one place to the back
+            let mut i = index;
+            while i > 0 {
+                // SAFETY: The place at `i` was freed by reading or by the previous move
+                unsafe { repr.move_item(i - 1, i) };
+                i -= 1;
+            }
+            repr.head = repr.phys_idx_of(1);
+        } else {
+            // Move the items after `index` one place to the front
+            let mut i = index + 1;
+            while i < len {
+                // SAFETY: The place at `i - 1` was freed by reading or by the previous move
+                unsafe { repr.move_item(i, i - 1) };
+                i += 1;
+            }
+        }
+        repr.len -= 1;
+        Some(item)
+    }
+
+    /// Equivalent of
+    /// [`VecDeque::swap_remove_front`](std::collections::VecDeque::swap_remove_front).
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::array::*;
+    ///
+    /// let mut deq = ArrDeqApi::new_full([1, 2, 3, 4]);
+    /// assert_eq!(deq.swap_remove_front(2), Some(3));
+    /// assert_eq!(deq, [2, 1, 4]);
+    /// ```
+    pub const fn swap_remove_front(&mut self, index: usize) -> Option<T> {
+        if index >= self.len() {
+            return None;
+        }
+        self.swap(index, 0);
+        self.pop_front()
+    }
+
+    /// Equivalent of
+    /// [`VecDeque::swap_remove_back`](std::collections::VecDeque::swap_remove_back).
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::array::*;
+    ///
+    /// let mut deq = ArrDeqApi::new_full([1, 2, 3, 4]);
+    /// assert_eq!(deq.swap_remove_back(1), Some(2));
+    /// assert_eq!(deq, [1, 4, 3]);
+    /// ```
+    pub const fn swap_remove_back(&mut self, index: usize) -> Option<T> {
+        let len = self.len();
+        if index >= len {
+            return None;
+        }
+        self.swap(index, len - 1);
+        self.pop_back()
+    }
+
+    /// Equivalent of [`VecDeque::rotate_left`](std::collections::VecDeque::rotate_left).
+    ///
+    /// This moves `min(n, len - n)` items, or none at all if the deque is full.
+    ///
+    /// # Panics
+    /// If `n > len`.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::array::*;
+    ///
+    /// let mut deq = ArrDeqApi::<[_; 6]>::from_iter(0..5);
+    /// deq.rotate_left(2);
+    /// assert_eq!(deq, [2, 3, 4, 0, 1]);
+    /// deq.rotate_left(4);
+    /// assert_eq!(deq, [1, 2, 3, 4, 0]);
+    /// ```
+    #[track_caller]
+    pub const fn rotate_left(&mut self, n: usize) {
+        let len = self.len();
+        if n > len {
+            const_fmt::fmt![
+                "rotation amount (is ",
+                n,
+                ") should be <= len (is ",
+                len,
+                ")"
+            ]
+            .panic()
+        }
+        if n <= len - n {
+            self.rotate_left_inner(n);
+        } else {
+            self.rotate_right_inner(len - n);
+        }
+    }
+
+    /// Equivalent of [`VecDeque::rotate_right`](std::collections::VecDeque::rotate_right).
+    ///
+    /// This moves `min(n, len - n)` items, or none at all if the deque is full.
+    ///
+    /// # Panics
+    /// If `n > len`.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::array::*;
+    ///
+    /// let mut deq = ArrDeqApi::new_full([0, 1, 2, 3, 4]);
+    /// deq.rotate_right(2);
+    /// assert_eq!(deq, [3, 4, 0, 1, 2]);
+    /// ```
+    #[track_caller]
+    pub const fn rotate_right(&mut self, n: usize) {
+        let len = self.len();
+        if n > len {
+            const_fmt::fmt![
+                "rotation amount (is ",
+                n,
+                ") should be <= len (is ",
+                len,
+                ")"
+            ]
+            .panic()
+        }
+        if n <= len - n {
+            self.rotate_right_inner(n);
+        } else {
+            self.rotate_left_inner(len - n);
+        }
+    }
+
+    /// Moves the first `n <= len` items to the back.
+    const fn rotate_left_inner(&mut self, n: usize) {
+        let full = self.is_full();
+        // SAFETY: See below
+        let repr = unsafe { self.as_mut_repr() };
+        if full {
+            // All places are occupied, so moving the head is enough
+            repr.head = repr.phys_idx_of(n);
+            return;
+        }
+        let mut i = 0;
+        while i < n {
+            // SAFETY: `0 < len < cap`, so the item at `0` is valid and the place at `len` is
+            // spare. Moving the head then makes the moved item the last one.
+            unsafe { repr.move_item(0, repr.len) };
+            repr.head = repr.phys_idx_of(1);
+            i += 1;
+        }
+    }
+
+    /// Moves the last `n <= len` items to the front.
+    const fn rotate_right_inner(&mut self, n: usize) {
+        let full = self.is_full();
+        // SAFETY: See below
+        let repr = unsafe { self.as_mut_repr() };
+        if full {
+            // All places are occupied, so moving the head is enough
+            repr.head = repr.phys_idx_before_head(n);
+            return;
+        }
+        let mut i = 0;
+        while i < n {
+            // The spare place before the head is now at `0` and the last item at `len`
+            repr.head = repr.phys_idx_before_head(1);
+            // SAFETY: `0 < len < cap`, so the item at `len` is valid and the place at `0`
+            // is spare
+            unsafe { repr.move_item(repr.len, 0) };
+            i += 1;
+        }
+    }
+
+    /// Equivalent of [`VecDeque::truncate`](std::collections::VecDeque::truncate).
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::array::*;
+    ///
+    /// let mut deq = ArrDeqApi::<[_; 4]>::from_iter([1, 2, 3]);
+    /// deq.push_front(0);
+    /// deq.truncate(5);
+    /// assert_eq!(deq, [0, 1, 2, 3]);
+    /// deq.truncate(1);
+    /// assert_eq!(deq, [0]);
+    /// ```
+    pub fn truncate(&mut self, len: usize) {
+        /// Drops the second part even if dropping the first one panics.
+        struct DropSlice<T>(*mut [T]);
+        impl<T> Drop for DropSlice<T> {
+            fn drop(&mut self) {
+                // SAFETY: Only used with items that are no longer owned by the deque
+                unsafe { ptr::drop_in_place(self.0) }
+            }
+        }
+
+        let old_len = self.len();
+        if len >= old_len {
+            return;
+        }
+        // SAFETY: The first `len` items stay valid. The length is set first so that the
+        // remaining items are not dropped again if dropping one of them panics.
+        let repr = unsafe { self.as_mut_repr() };
+        repr.len = len;
+        let (front, back) = slice_ranges(repr.phys_idx_of(len), old_len - len, arr_len::<A>());
+        let buf = repr.arr.as_mut_slice().as_mut_ptr().cast::<T>();
+        // SAFETY: The ranges are those of the removed items, which are in bounds
+        let (front, back) = unsafe {
+            (
+                ptr::slice_from_raw_parts_mut(buf.add(front.start), front.len()),
+                ptr::slice_from_raw_parts_mut(buf.add(back.start), back.len()),
+            )
+        };
+        let _back = DropSlice(back);
+        // SAFETY: These items were valid and are no longer owned by the deque
+        unsafe { ptr::drop_in_place(front) }
+    }
+
+    /// Equivalent of [`VecDeque::clear`](std::collections::VecDeque::clear).
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::array::*;
+    ///
+    /// let mut deq = ArrDeqApi::new_full([1, 2, 3]);
+    /// deq.clear();
+    /// assert!(deq.is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        self.truncate(0);
+        // SAFETY: The deque is empty
+        unsafe { self.as_mut_repr() }.head = 0;
+    }
+
+    /// Equivalent of [`VecDeque::retain`](std::collections::VecDeque::retain).
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::array::*;
+    ///
+    /// let mut deq = ArrDeqApi::new_full([1, 2, 3, 4, 5]);
+    /// deq.pop_front();
+    /// deq.push_back(6);
+    /// deq.retain(|&x| x % 2 == 0);
+    /// assert_eq!(deq, [2, 4, 6]);
+    /// ```
+    pub fn retain(&mut self, mut f: impl FnMut(&T) -> bool) {
+        self.retain_mut(|item| f(item));
+    }
+
+    /// Equivalent of [`VecDeque::retain_mut`](std::collections::VecDeque::retain_mut).
+    pub fn retain_mut(&mut self, mut f: impl FnMut(&mut T) -> bool) {
+        let mut kept = 0;
+        for i in 0..self.len() {
+            if f(&mut self[i]) {
+                self.swap(kept, i);
+                kept += 1;
+            }
+        }
+        self.truncate(kept);
+    }
+
+    /// Equivalent of [`VecDeque::resize`](std::collections::VecDeque::resize).
+    ///
+    /// # Panics
+    /// If `new_len` exceeds the capacity.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::array::*;
+    ///
+    /// let mut deq = ArrDeqApi::<[_; 4]>::new();
+    /// deq.push_front('a');
+    /// deq.resize(3, 'x');
+    /// assert_eq!(deq, ['a', 'x', 'x']);
+    /// deq.resize(1, 'y');
+    /// assert_eq!(deq, ['a']);
+    /// ```
+    #[track_caller]
+    pub fn resize(&mut self, new_len: usize, value: T)
+    where
+        T: Clone,
+    {
+        let len = self.len();
+        if new_len <= len {
+            self.truncate(new_len);
+            return;
+        }
+        assert!(
+            new_len <= self.capacity(),
+            "Call to `resize` on `ArrDeqApi` with insufficient capacity"
+        );
+        for _ in len + 1..new_len {
+            self.push_back(value.clone());
+        }
+        self.push_back(value);
+    }
+
+    /// Equivalent of [`VecDeque::split_off`](std::collections::VecDeque::split_off).
+    ///
+    /// # Panics
+    /// If `at > len`.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::array::*;
+    ///
+    /// let mut deq = ArrDeqApi::<[_; 4]>::from_iter([1, 2, 3]);
+    /// deq.push_front(0);
+    /// let tail = deq.split_off(1);
+    /// assert_eq!(deq, [0]);
+    /// assert_eq!(tail, [1, 2, 3]);
+    /// ```
+    #[track_caller]
+    pub const fn split_off(&mut self, at: usize) -> Self {
+        let len = self.len();
+        if at > len {
+            const_fmt::fmt![
+                "`at` split index (is ",
+                at,
+                ") should be <= len (is ",
+                len,
+                ")"
+            ]
+            .panic()
+        }
+
+        let mut other = Self::new();
+        // SAFETY: See below
+        let repr = unsafe { self.as_mut_repr() };
+        let mut i = at;
+        while i < len {
+            // SAFETY: The item at `i` is valid and given up by setting the length below.
+            // `other` has the same capacity, so pushing does not panic.
+            other.push_back(unsafe { repr.phys_read(repr.phys_idx_of(i)) });
+            i += 1;
+        }
+        repr.len = at;
+        other
+    }
+
+    /// Equivalent of [`VecDeque::append`](std::collections::VecDeque::append), but `other`
+    /// may have a different capacity.
+    ///
+    /// # Panics
+    /// If the items of `other` don't fit into the deque. `other` is left unchanged in this
+    /// case.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::array::*;
+    ///
+    /// let mut deq = ArrDeqApi::<[_; 5]>::from_iter([1, 2]);
+    /// let mut other = ArrDeqApi::new_full([3, 4, 5]);
+    /// deq.append(&mut other);
+    /// assert_eq!(deq, [1, 2, 3, 4, 5]);
+    /// assert!(other.is_empty());
+    /// ```
+    #[track_caller]
+    pub const fn append<B: Array<Item = T>>(&mut self, other: &mut ArrDeqApi<B>) {
+        if other.len() > self.capacity() - self.len() {
+            panic!("Call to `append` on `ArrDeqApi` with insufficient capacity")
+        }
+        // SAFETY: See below
+        let other = unsafe { other.as_mut_repr() };
+        let mut i = 0;
+        while i < other.len {
+            // SAFETY: The item at `i` is valid and given up by setting the length below.
+            // There is enough capacity, so pushing does not panic.
+            self.push_back(unsafe { other.phys_read(other.phys_idx_of(i)) });
+            i += 1;
+        }
+        other.len = 0;
+        other.head = 0;
+    }
+}
+
+/// Splits a logical range of a deque into ranges of its two slices, given the length of the
+/// first one.
+#[track_caller]
+fn split_range(
+    range: impl RangeBounds<usize>,
+    lhs_len: usize,
+    len: usize,
+) -> (Range<usize>, Range<usize>) {
+    let Range { start, end } = slice_range(range, len);
+    (
+        start.min(lhs_len)..end.min(lhs_len),
+        start.saturating_sub(lhs_len)..end.saturating_sub(lhs_len),
+    )
+}
+
 mod core_impl;
+mod drain;
@@ -1,4 +1,6 @@
-use crate::array::{ArrDeqApi, Array};
+use core::{cmp::Ordering, hash::Hash};
+
+use crate::array::{ArrDeqApi, ArrVecApi, Array};
 
 impl<A: Array, U> PartialEq<[U]> for ArrDeqApi<A>
 where
@@ -44,6 +46,97 @@ where
     }
 }
 
+impl<A: Array, B: Array> PartialEq<ArrDeqApi<B>> for ArrDeqApi<A>
+where
+    A::Item: PartialEq<B::Item>,
+{
+    fn eq(&self, other: &ArrDeqApi<B>) -> bool {
+        self.len() == other.len() && self.into_iter().eq(other)
+    }
+}
+
+impl<A: Array, B: Array> PartialEq<ArrVecApi<B>> for ArrDeqApi<A>
+where
+    A::Item: PartialEq<B::Item>,
+{
+    fn eq(&self, other: &ArrVecApi<B>) -> bool {
+        self == other.as_slice()
+    }
+}
+
+impl<A: Array> Eq for ArrDeqApi<A> where A::Item: Eq {}
+
+impl<A: Array> PartialOrd for ArrDeqApi<A>
+where
+    A::Item: PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.into_iter().partial_cmp(other)
+    }
+}
+
+impl<A: Array> Ord for ArrDeqApi<A>
+where
+    A::Item: Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.into_iter().cmp(other)
+    }
+}
+
+impl<A: Array> Hash for ArrDeqApi<A>
+where
+    A::Item: Hash,
+{
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len());
+        self.into_iter().for_each(|item| item.hash(state));
+    }
+}
+
+impl<A: Array> Clone for ArrDeqApi<A>
+where
+    A::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        self.into_iter().cloned().collect()
+    }
+}
+
+impl<A: Array> core::ops::Index<usize> for ArrDeqApi<A> {
+    type Output = A::Item;
+    #[track_caller]
+    fn index(&self, index: usize) -> &A::Item {
+        let len = self.len();
+        assert!(
+            index < len,
+            "index out of bounds: the len is {len} but the index is {index}"
+        );
+        let (lhs, rhs) = self.as_slices();
+        if index < lhs.len() {
+            &lhs[index]
+        } else {
+            &rhs[index - lhs.len()]
+        }
+    }
+}
+impl<A: Array> core::ops::IndexMut<usize> for ArrDeqApi<A> {
+    #[track_caller]
+    fn index_mut(&mut self, index: usize) -> &mut A::Item {
+        let len = self.len();
+        assert!(
+            index < len,
+            "index out of bounds: the len is {len} but the index is {index}"
+        );
+        let (lhs, rhs) = self.as_mut_slices();
+        if index < lhs.len() {
+            &mut lhs[index]
+        } else {
+            &mut rhs[index - lhs.len()]
+        }
+    }
+}
+
 impl<A: Array> core::fmt::Debug for ArrDeqApi<A>
 where
     A::Item: core::fmt::Debug,
@@ -59,3 +152,35 @@ impl<A: Array> Default for ArrDeqApi<A> {
         Self::new()
     }
 }
+
+/// Panics if the iterator yields more items than fit into the deque.
+impl<A: Array<Item = T>, T> FromIterator<T> for ArrDeqApi<A> {
+    #[track_caller]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut this = Self::new();
+        this.extend(iter);
+        this
+    }
+}
+
+/// Panics if the iterator yields more items than fit into the deque.
+///
+/// ```should_panic
+/// use genuint::array::*;
+///
+/// let mut deq = ArrDeqApi::<[_; 2]>::new();
+/// deq.extend([1, 2, 3]);
+/// ```
+impl<A: Array<Item = T>, T> Extend<T> for ArrDeqApi<A> {
+    #[track_caller]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for (accepted, item) in iter.into_iter().enumerate() {
+            if self.try_push_back(item).is_err() {
+                panic!(
+                    "Call to `extend` on `ArrDeqApi` with insufficient capacity, \
+                    deque was full after accepting {accepted} items"
+                )
+            }
+        }
+    }
+}
@@ -95,4 +95,17 @@ impl<A: Array> ArrDeqRepr<A> {
         // SAFETY: `idx` is initialized and never used again
         unsafe { self.arr.as_slice()[idx].assume_init_read() }
     }
+
+    /// Moves the item at logical index `src` to logical index `dst`.
+    ///
+    /// # Safety
+    /// Both indices must be less than the capacity and distinct. The item at `src` must be
+    /// valid and the place at `dst` must not hold a valid item. Afterwards, the place at `src`
+    /// does not hold a valid item.
+    pub(crate) const unsafe fn move_item(&mut self, src: usize, dst: usize) {
+        let (src, dst) = (self.phys_idx_of(src), self.phys_idx_of(dst));
+        let buf = self.arr.as_mut_slice().as_mut_ptr();
+        // SAFETY: Both indices are in bounds and distinct, guaranteed by the caller
+        unsafe { core::ptr::copy_nonoverlapping(buf.add(src), buf.add(dst), 1) }
+    }
 }
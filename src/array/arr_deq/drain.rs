@@ -0,0 +1,164 @@
+use core::{iter::FusedIterator, ops::RangeBounds};
+
+use crate::array::{helper::*, *};
+
+use super::ArrDeqRepr;
+
+/// Iterator returned by [`ArrDeqApi::drain`].
+///
+/// When dropped, the remaining drained items are dropped and the gap is closed by moving
+/// whichever side of the deque is shorter. If the iterator is leaked, the deque only keeps the
+/// items before the drained range.
+pub struct Drain<'a, A: Array> {
+    /// The length of `deq` is the start of the drained range while this exists.
+    deq: &'a mut ArrDeqApi<A>,
+    /// The logical indices `front..back` are yet to be yielded.
+    front: usize,
+    back: usize,
+    tail_len: usize,
+    drain_len: usize,
+}
+
+impl<A: Array<Item = T>, T> Drain<'_, A> {
+    /// # Safety
+    /// The deque must not be used as long as the result is.
+    const unsafe fn repr(&mut self) -> &mut ArrDeqRepr<A> {
+        // SAFETY: Upheld by the caller
+        unsafe { self.deq.as_mut_repr() }
+    }
+
+    /// Moves the item at logical index `src` to logical index `dst`.
+    ///
+    /// # Safety
+    /// The item at `src` must be valid and the place at `dst` must not hold a valid item.
+    const unsafe fn move_item(&mut self, src: usize, dst: usize) {
+        // SAFETY: The length and head are not modified. The rest is upheld by the caller.
+        unsafe { self.repr().move_item(src, dst) }
+    }
+}
+
+impl<A: Array<Item = T>, T> Iterator for Drain<'_, A> {
+    type Item = T;
+    fn next(&mut self) -> Option<T> {
+        if self.front == self.back {
+            return None;
+        }
+        let idx = self.front;
+        self.front += 1;
+        // SAFETY: The deque is not used otherwise
+        let repr = unsafe { self.repr() };
+        // SAFETY: The item at `idx` is valid and no longer considered part of the range
+        Some(unsafe { repr.phys_read(repr.phys_idx_of(idx)) })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+impl<A: Array<Item = T>, T> DoubleEndedIterator for Drain<'_, A> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        let idx = self.back;
+        // SAFETY: The deque is not used otherwise
+        let repr = unsafe { self.repr() };
+        // SAFETY: The item at `idx` is valid and no longer considered part of the range
+        Some(unsafe { repr.phys_read(repr.phys_idx_of(idx)) })
+    }
+}
+impl<A: Array> ExactSizeIterator for Drain<'_, A> {}
+impl<A: Array> FusedIterator for Drain<'_, A> {}
+
+impl<A: Array> Drop for Drain<'_, A> {
+    fn drop(&mut self) {
+        /// Closes the gap, even if dropping one of the remaining items panics.
+        struct CloseGap<'r, 'a, A: Array>(&'r mut Drain<'a, A>);
+        impl<A: Array> Drop for CloseGap<'_, '_, A> {
+            fn drop(&mut self) {
+                let drain = &mut *self.0;
+                let head_len = drain.deq.len();
+                let (tail_len, drain_len) = (drain.tail_len, drain.drain_len);
+                if drain_len == 0 {
+                    // SAFETY: The tail directly follows the head
+                    unsafe { drain.repr() }.len = head_len + tail_len;
+                    return;
+                }
+
+                if head_len <= tail_len {
+                    // Move the head back, starting with its last item
+                    for i in (0..head_len).rev() {
+                        // SAFETY: The item at `i + drain_len` was moved or drained
+                        unsafe { drain.move_item(i, i + drain_len) };
+                    }
+                    // SAFETY: See below
+                    let repr = unsafe { drain.repr() };
+                    // The items now start at `drain_len` and are contiguous
+                    repr.head = repr.phys_idx_of(drain_len);
+                    repr.len = head_len + tail_len;
+                } else {
+                    // Move the tail forward, starting with its first item
+                    let tail_start = head_len + drain_len;
+                    for i in 0..tail_len {
+                        // SAFETY: The item at `head_len + i` was moved or drained
+                        unsafe { drain.move_item(tail_start + i, head_len + i) };
+                    }
+                    // SAFETY: The tail now directly follows the head
+                    unsafe { drain.repr() }.len = head_len + tail_len;
+                }
+            }
+        }
+
+        let guard = CloseGap(self);
+        guard.0.for_each(drop);
+    }
+}
+
+impl<A: Array<Item = T>, T> ArrDeqApi<A> {
+    /// Equivalent of [`VecDeque::drain`](std::collections::VecDeque::drain).
+    ///
+    /// # Panics
+    /// If the start of the range is greater than its end or if the end is greater than the
+    /// length of the deque.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::array::*;
+    ///
+    /// let mut deq = ArrDeqApi::new_full([1, 2, 3, 4, 5]);
+    /// deq.pop_front();
+    /// deq.push_back(6);
+    /// let batch = deq.drain(..2).collect::<ArrVecApi<[_; 5]>>();
+    /// assert_eq!(batch, [2, 3]);
+    /// assert_eq!(deq, [4, 5, 6]);
+    ///
+    /// // Unyielded items are dropped
+    /// deq.drain(1..);
+    /// assert_eq!(deq, [4]);
+    /// ```
+    ///
+    /// Leaking the iterator leaks the items after the start of the range.
+    /// ```
+    /// use genuint::array::*;
+    ///
+    /// let mut deq = ArrDeqApi::new_full([1, 2, 3, 4, 5]);
+    /// core::mem::forget(deq.drain(2..3));
+    /// assert_eq!(deq, [1, 2]);
+    /// ```
+    #[track_caller]
+    pub fn drain(&mut self, range: impl RangeBounds<usize>) -> Drain<'_, A> {
+        let len = self.len();
+        let range = slice_range(range, len);
+        // SAFETY: The first `range.start` items stay valid. The others are now owned by the
+        // `Drain`, which restores the length when dropped.
+        unsafe { self.as_mut_repr() }.len = range.start;
+        Drain {
+            deq: self,
+            front: range.start,
+            back: range.end,
+            tail_len: len - range.end,
+            drain_len: range.end - range.start,
+        }
+    }
+}
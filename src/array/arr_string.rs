@@ -0,0 +1,381 @@
+use core::{cmp::Ordering, fmt, hash::Hash, str::FromStr};
+
+use crate::{
+    Uint,
+    array::{ArrString, ArrVec, StrCapacityError},
+};
+
+impl<N: Uint> ArrString<N> {
+    /// Creates an empty string.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::{array::*, small::*};
+    ///
+    /// let string = ArrString::<U8>::new();
+    /// assert_eq!(string, "");
+    /// assert_eq!(string.capacity(), 8);
+    /// ```
+    pub const fn new() -> Self {
+        Self { vec: ArrVec::new() }
+    }
+
+    /// Creates a string from a vector of UTF-8 bytes.
+    ///
+    /// # Errors
+    /// If `vec` is not valid UTF-8.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::{array::*, small::*};
+    ///
+    /// let vec = ArrVec::<u8, U4>::from_iter(*b"abc");
+    /// assert_eq!(ArrString::from_utf8(vec).unwrap(), "abc");
+    ///
+    /// let vec = ArrVec::<u8, U4>::from_iter([0xFF]);
+    /// assert!(ArrString::from_utf8(vec).is_err());
+    /// ```
+    pub const fn from_utf8(vec: ArrVec<u8, N>) -> Result<Self, core::str::Utf8Error> {
+        match core::str::from_utf8(vec.as_slice()) {
+            // SAFETY: `vec` is valid UTF-8
+            Ok(_) => Ok(unsafe { Self::from_utf8_unchecked(vec) }),
+            Err(err) => {
+                // `u8` has no drop glue
+                core::mem::forget(vec);
+                Err(err)
+            }
+        }
+    }
+
+    /// Creates a string from a vector of UTF-8 bytes without checking that it is valid UTF-8.
+    ///
+    /// # Safety
+    /// The items of `vec` must be valid UTF-8.
+    pub const unsafe fn from_utf8_unchecked(vec: ArrVec<u8, N>) -> Self {
+        Self { vec }
+    }
+
+    /// Returns the UTF-8 bytes of the string as a vector.
+    pub const fn into_bytes(mut self) -> ArrVec<u8, N> {
+        // An empty vector is valid UTF-8
+        #[allow(clippy::mem_replace_with_default)] // `mem::take` is not const
+        let vec = core::mem::replace(&mut self.vec, ArrVec::new());
+        // `self` is empty now, so this does not leak anything
+        core::mem::forget(self);
+        vec
+    }
+
+    /// Returns the string as a `&str`.
+    pub const fn as_str(&self) -> &str {
+        // SAFETY: The bytes are valid UTF-8 by invariant
+        unsafe { core::str::from_utf8_unchecked(self.vec.as_slice()) }
+    }
+
+    /// Returns the string as a `&mut str`.
+    pub const fn as_mut_str(&mut self) -> &mut str {
+        // SAFETY: The bytes are valid UTF-8 by invariant. `&mut str` can only be used to write
+        // valid UTF-8.
+        unsafe { core::str::from_utf8_unchecked_mut(self.vec.as_mut_slice()) }
+    }
+
+    /// Returns the length of the string in bytes.
+    pub const fn len(&self) -> usize {
+        self.vec.len()
+    }
+
+    /// Checks whether the string is empty.
+    pub const fn is_empty(&self) -> bool {
+        self.vec.is_empty()
+    }
+
+    /// Returns the capacity of the string in bytes, i.e. `N`.
+    pub const fn capacity(&self) -> usize {
+        self.vec.capacity()
+    }
+
+    /// Checks whether the string is full.
+    pub const fn is_full(&self) -> bool {
+        self.vec.is_full()
+    }
+
+    /// Returns the number of bytes that can be pushed until the string is full.
+    pub const fn spare_len(&self) -> usize {
+        self.vec.spare_len()
+    }
+
+    /// Like [`push_str`](Self::push_str), but returns an error if `s` does not fit.
+    ///
+    /// # Errors
+    /// If the string does not have enough capacity left for `s`. The string is left unchanged
+    /// in this case.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::{array::*, small::*};
+    ///
+    /// let mut string = ArrString::<U4>::new();
+    /// assert!(string.try_push_str("abc").is_ok());
+    /// assert_eq!(
+    ///     string.try_push_str("de"),
+    ///     Err(StrCapacityError { len: 2, spare: 1 })
+    /// );
+    /// assert_eq!(string, "abc");
+    /// ```
+    pub const fn try_push_str(&mut self, s: &str) -> Result<(), StrCapacityError> {
+        let spare = self.spare_len();
+        if s.len() > spare {
+            return Err(StrCapacityError {
+                len: s.len(),
+                spare,
+            });
+        }
+        let bytes = s.as_bytes();
+        let mut i = 0;
+        while i < bytes.len() {
+            self.vec.push(bytes[i]);
+            i += 1;
+        }
+        Ok(())
+    }
+
+    /// Equivalent of [`String::push_str`].
+    ///
+    /// # Panics
+    /// If the string does not have enough capacity left for `s`.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::{array::*, small::*};
+    ///
+    /// const GREETING: ArrString<U16> = {
+    ///     let mut string = ArrString::new();
+    ///     string.push_str("hello");
+    ///     string.push(' ');
+    ///     string.push_str("world");
+    ///     string
+    /// };
+    /// assert_eq!(GREETING, "hello world");
+    /// ```
+    #[track_caller]
+    pub const fn push_str(&mut self, s: &str) {
+        if self.try_push_str(s).is_err() {
+            panic!("Call to `push_str` on `ArrString` with insufficient capacity")
+        }
+    }
+
+    /// Like [`push`](Self::push), but returns an error if `c` does not fit.
+    ///
+    /// # Errors
+    /// Returns back `c` if the string does not have enough capacity left for it.
+    pub const fn try_push(&mut self, c: char) -> Result<(), char> {
+        match self.try_push_str(c.encode_utf8(&mut [0; 4])) {
+            Ok(()) => Ok(()),
+            Err(_) => Err(c),
+        }
+    }
+
+    /// Equivalent of [`String::push`].
+    ///
+    /// # Panics
+    /// If the string does not have enough capacity left for `c`.
+    #[track_caller]
+    pub const fn push(&mut self, c: char) {
+        if self.try_push(c).is_err() {
+            panic!("Call to `push` on `ArrString` with insufficient capacity")
+        }
+    }
+
+    /// Equivalent of [`String::pop`].
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::array::*;
+    ///
+    /// let mut string = arr_str!("añ");
+    /// assert_eq!(string.pop(), Some('ñ'));
+    /// assert_eq!(string.pop(), Some('a'));
+    /// assert_eq!(string.pop(), None);
+    /// ```
+    pub fn pop(&mut self) -> Option<char> {
+        let c = self.as_str().chars().next_back()?;
+        self.truncate(self.len() - c.len_utf8());
+        Some(c)
+    }
+
+    /// Equivalent of [`String::truncate`], but `const`.
+    ///
+    /// # Panics
+    /// If `new_len` does not lie on a [`char`] boundary.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::array::*;
+    ///
+    /// let mut string = arr_str!("hello");
+    /// string.truncate(2);
+    /// assert_eq!(string, "he");
+    /// ```
+    #[track_caller]
+    pub const fn truncate(&mut self, new_len: usize) {
+        if new_len < self.len() {
+            assert!(
+                self.as_str().is_char_boundary(new_len),
+                "`new_len` does not lie on a char boundary"
+            );
+            // SAFETY: The first `new_len` bytes are initialized and form valid UTF-8, since
+            // `new_len` is a char boundary
+            unsafe { self.vec.set_len(new_len) }
+        }
+    }
+
+    /// Equivalent of [`String::clear`], but `const`.
+    pub const fn clear(&mut self) {
+        self.truncate(0);
+    }
+}
+
+impl<N: Uint> core::ops::Deref for ArrString<N> {
+    type Target = str;
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+impl<N: Uint> core::ops::DerefMut for ArrString<N> {
+    fn deref_mut(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+}
+impl<N: Uint> AsRef<str> for ArrString<N> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+impl<N: Uint> AsMut<str> for ArrString<N> {
+    fn as_mut(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+}
+impl<N: Uint> AsRef<[u8]> for ArrString<N> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+impl<N: Uint> core::borrow::Borrow<str> for ArrString<N> {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+impl<N: Uint> core::borrow::BorrowMut<str> for ArrString<N> {
+    fn borrow_mut(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+}
+
+impl<N: Uint> fmt::Write for ArrString<N> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.try_push_str(s).map_err(|_| fmt::Error)
+    }
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        self.try_push(c).map_err(|_| fmt::Error)
+    }
+}
+impl<N: Uint> fmt::Display for ArrString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+impl<N: Uint> fmt::Debug for ArrString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<N: Uint> FromStr for ArrString<N> {
+    type Err = StrCapacityError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut this = Self::new();
+        this.try_push_str(s)?;
+        Ok(this)
+    }
+}
+impl<N: Uint> TryFrom<&str> for ArrString<N> {
+    type Error = StrCapacityError;
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl<N: Uint> Default for ArrString<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<N: Uint> Clone for ArrString<N> {
+    fn clone(&self) -> Self {
+        Self {
+            vec: self.vec.clone(),
+        }
+    }
+}
+
+impl<N: Uint, M: Uint> PartialEq<ArrString<M>> for ArrString<N> {
+    fn eq(&self, other: &ArrString<M>) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+impl<N: Uint> Eq for ArrString<N> {}
+impl<N: Uint> PartialEq<str> for ArrString<N> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+impl<N: Uint> PartialEq<&str> for ArrString<N> {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+impl<N: Uint> PartialEq<ArrString<N>> for str {
+    fn eq(&self, other: &ArrString<N>) -> bool {
+        self == other.as_str()
+    }
+}
+impl<N: Uint> PartialEq<ArrString<N>> for &str {
+    fn eq(&self, other: &ArrString<N>) -> bool {
+        *self == other.as_str()
+    }
+}
+impl<N: Uint, M: Uint> PartialOrd<ArrString<M>> for ArrString<N> {
+    fn partial_cmp(&self, other: &ArrString<M>) -> Option<Ordering> {
+        Some(self.as_str().cmp(other.as_str()))
+    }
+}
+impl<N: Uint> Ord for ArrString<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+impl<N: Uint> PartialOrd<str> for ArrString<N> {
+    fn partial_cmp(&self, other: &str) -> Option<Ordering> {
+        Some(self.as_str().cmp(other))
+    }
+}
+impl<N: Uint> PartialOrd<ArrString<N>> for str {
+    fn partial_cmp(&self, other: &ArrString<N>) -> Option<Ordering> {
+        Some(self.cmp(other.as_str()))
+    }
+}
+impl<N: Uint> PartialOrd<&str> for ArrString<N> {
+    fn partial_cmp(&self, other: &&str) -> Option<Ordering> {
+        Some(self.as_str().cmp(*other))
+    }
+}
+impl<N: Uint> PartialOrd<ArrString<N>> for &str {
+    fn partial_cmp(&self, other: &ArrString<N>) -> Option<Ordering> {
+        Some((*self).cmp(other.as_str()))
+    }
+}
+impl<N: Uint> Hash for ArrString<N> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
@@ -1,6 +1,7 @@
 use core::{marker::PhantomData, mem::MaybeUninit};
 
 mod core_impl;
+mod drain;
 
 use const_util::result::expect_ok;
 
@@ -443,6 +444,66 @@ where
         Ok(())
     }
 
+    /// Pushes the items of `iter` until it is exhausted or the vector is full.
+    ///
+    /// Unlike the [`Extend`] impl, this does not panic if there are too many items.
+    ///
+    /// # Errors
+    /// If the iterator yields an item while the vector is full. The error contains this item
+    /// and the number of items that were pushed. The rest of the iterator is not consumed.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::array::*;
+    ///
+    /// let mut vec = ArrVecApi::<[_; 3]>::new();
+    /// assert_eq!(vec.try_extend([1, 2]), Ok(()));
+    ///
+    /// let mut iter = 3..10;
+    /// let err = vec.try_extend(&mut iter).unwrap_err();
+    /// assert_eq!((err.item, err.accepted), (4, 1));
+    /// assert_eq!(vec, [1, 2, 3]);
+    /// assert_eq!(iter.next(), Some(5));
+    /// ```
+    pub fn try_extend<I>(&mut self, iter: I) -> Result<(), CapacityError<T>>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        for (accepted, item) in iter.into_iter().enumerate() {
+            self.try_push(item)
+                .map_err(|item| CapacityError { item, accepted })?;
+        }
+        Ok(())
+    }
+
+    /// Creates a vector from the items of `iter`.
+    ///
+    /// Unlike the [`FromIterator`] impl, this does not panic if there are too many items.
+    ///
+    /// # Errors
+    /// If the iterator yields more items than fit into the vector. The error contains the
+    /// first item that did not fit. The items that did fit are dropped.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::array::*;
+    ///
+    /// let vec = ArrVecApi::<[_; 3]>::try_from_iter("ab".chars());
+    /// assert_eq!(vec.unwrap(), ['a', 'b']);
+    ///
+    /// let err = ArrVecApi::<[_; 3]>::try_from_iter("abcd".chars()).unwrap_err();
+    /// assert_eq!(err.item, 'd');
+    /// assert_eq!(err.to_string(), "insufficient capacity, vector was full after accepting 3 items");
+    /// ```
+    pub fn try_from_iter<I>(iter: I) -> Result<Self, CapacityError<T>>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut this = Self::new();
+        this.try_extend(iter)?;
+        Ok(this)
+    }
+
     /// Equivalent of [`Vec::pop`].
     ///
     /// # Examples
@@ -525,3 +586,395 @@ where
         }
     }
 }
+
+// `Vec` editing methods
+impl<A, T, N: Uint> ArrVecApi<A>
+where
+    A: Array<Item = T, Length = N>,
+{
+    /// Equivalent of [`Vec::insert`].
+    ///
+    /// # Panics
+    /// If `index > len` or if the vector is full.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::array::*;
+    ///
+    /// let mut vec = ArrVecApi::<[_; 4]>::new();
+    /// vec.push(1);
+    /// vec.push(3);
+    /// vec.insert(1, 2);
+    /// vec.insert(0, 0);
+    /// assert_eq!(vec, [0, 1, 2, 3]);
+    /// ```
+    ///
+    /// Editing works in `const`:
+    /// ```
+    /// use genuint::array::*;
+    ///
+    /// const EDITED: [i32; 3] = {
+    ///     let mut vec = ArrVecApi::new_full([1, 2, 3]);
+    ///     let first = vec.remove(0);
+    ///     vec.insert(1, first * 10);
+    ///     vec.assert_full()
+    /// };
+    /// assert_eq!(EDITED, [2, 10, 3]);
+    /// ```
+    #[track_caller]
+    pub const fn insert(&mut self, index: usize, item: T) {
+        let len = self.len();
+        if index > len {
+            const_fmt::fmt![
+                "insertion index (is ",
+                index,
+                ") should be <= len (is ",
+                len,
+                ")"
+            ]
+            .panic()
+        }
+        if self.is_full() {
+            panic!("Call to `insert` on full `ArrVecApi`")
+        }
+
+        // SAFETY: See below
+        let ArrVecRepr { arr, len } = unsafe { self.as_mut_repr() };
+        let ptr = arr.as_mut_slice().as_mut_ptr();
+        // SAFETY: `index <= len < capacity`, so both ranges are in bounds. The items in
+        // `index..len` are moved one place back, after which the item at `index` is logically
+        // uninitialized and is overwritten. We then own `len + 1` valid items.
+        unsafe {
+            core::ptr::copy(ptr.add(index), ptr.add(index + 1), *len - index);
+            ptr.add(index).write(MaybeUninit::new(item));
+        }
+        *len += 1;
+    }
+
+    /// Equivalent of [`Vec::remove`].
+    ///
+    /// # Panics
+    /// If `index >= len`.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::array::*;
+    ///
+    /// let mut vec = ArrVecApi::new_full([1, 2, 3]);
+    /// assert_eq!(vec.remove(1), 2);
+    /// assert_eq!(vec, [1, 3]);
+    /// ```
+    #[track_caller]
+    pub const fn remove(&mut self, index: usize) -> T {
+        let len = self.len();
+        if index >= len {
+            const_fmt::fmt![
+                "removal index (is ",
+                index,
+                ") should be < len (is ",
+                len,
+                ")"
+            ]
+            .panic()
+        }
+
+        // SAFETY: See below
+        let ArrVecRepr { arr, len } = unsafe { self.as_mut_repr() };
+        let ptr = arr.as_mut_slice().as_mut_ptr();
+        // SAFETY: `index < len`, so the item at `index` is valid. It is moved out, after which
+        // the items in `index + 1..len` are moved one place forward. We then own `len - 1`
+        // valid items.
+        let item = unsafe {
+            let item = ptr.add(index).read().assume_init();
+            core::ptr::copy(ptr.add(index + 1), ptr.add(index), *len - index - 1);
+            item
+        };
+        *len -= 1;
+        item
+    }
+
+    /// Equivalent of [`Vec::swap_remove`].
+    ///
+    /// # Panics
+    /// If `index >= len`.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::array::*;
+    ///
+    /// let mut vec = ArrVecApi::new_full([1, 2, 3, 4]);
+    /// assert_eq!(vec.swap_remove(0), 1);
+    /// assert_eq!(vec, [4, 2, 3]);
+    /// ```
+    #[track_caller]
+    pub const fn swap_remove(&mut self, index: usize) -> T {
+        let len = self.len();
+        if index >= len {
+            const_fmt::fmt![
+                "swap_remove index (is ",
+                index,
+                ") should be < len (is ",
+                len,
+                ")"
+            ]
+            .panic()
+        }
+        self.as_mut_slice().swap(index, len - 1);
+        self.remove(len - 1)
+    }
+
+    /// Equivalent of [`Vec::truncate`].
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::array::*;
+    ///
+    /// let mut vec = ArrVecApi::new_full([1, 2, 3]);
+    /// vec.truncate(5);
+    /// assert_eq!(vec, [1, 2, 3]);
+    /// vec.truncate(1);
+    /// assert_eq!(vec, [1]);
+    /// ```
+    pub fn truncate(&mut self, len: usize) {
+        let old_len = self.len();
+        if len >= old_len {
+            return;
+        }
+        // SAFETY: The first `len` items stay valid. The length is set first so that the
+        // remaining items are not dropped again if dropping one of them panics.
+        unsafe { self.set_len(len) };
+        let tail = core::ptr::slice_from_raw_parts_mut(
+            self.spare_capacity_mut().as_mut_ptr().cast::<T>(),
+            old_len - len,
+        );
+        // SAFETY: These items were valid and are no longer owned by the vector
+        unsafe { core::ptr::drop_in_place(tail) }
+    }
+
+    /// Equivalent of [`Vec::clear`].
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::array::*;
+    ///
+    /// let mut vec = ArrVecApi::new_full([1, 2, 3]);
+    /// vec.clear();
+    /// assert!(vec.is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Equivalent of [`Vec::retain`].
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::array::*;
+    ///
+    /// let mut vec = ArrVecApi::new_full([1, 2, 3, 4, 5]);
+    /// vec.retain(|&x| x % 2 == 1);
+    /// assert_eq!(vec, [1, 3, 5]);
+    /// ```
+    ///
+    /// Removed items are dropped.
+    /// ```
+    /// use genuint::array::*;
+    /// use std::rc::Rc;
+    ///
+    /// let rc = Rc::new(());
+    /// let mut vec = ArrVecApi::<[_; 4]>::from_iter([0, 1, 2, 3].map(|i| (i, rc.clone())));
+    /// vec.retain(|(i, _)| *i == 2);
+    /// assert_eq!(Rc::strong_count(&rc), 2);
+    /// assert_eq!(vec.as_slice()[0].0, 2);
+    /// ```
+    pub fn retain(&mut self, mut f: impl FnMut(&T) -> bool) {
+        self.retain_mut(|item| f(item));
+    }
+
+    /// Equivalent of [`Vec::retain_mut`].
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::array::*;
+    ///
+    /// let mut vec = ArrVecApi::new_full([1, 2, 3, 4]);
+    /// vec.retain_mut(|x| {
+    ///     *x *= 10;
+    ///     *x > 20
+    /// });
+    /// assert_eq!(vec, [30, 40]);
+    /// ```
+    pub fn retain_mut(&mut self, mut f: impl FnMut(&mut T) -> bool) {
+        let slice = self.as_mut_slice();
+        let mut kept = 0;
+        for i in 0..slice.len() {
+            if f(&mut slice[i]) {
+                slice.swap(kept, i);
+                kept += 1;
+            }
+        }
+        self.truncate(kept);
+    }
+
+    /// Equivalent of [`Vec::dedup_by`].
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::array::*;
+    ///
+    /// let mut vec = ArrVecApi::new_full(["a", "A", "b", "B", "b"]);
+    /// vec.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+    /// assert_eq!(vec, ["a", "b"]);
+    /// ```
+    pub fn dedup_by(&mut self, mut same_bucket: impl FnMut(&mut T, &mut T) -> bool) {
+        let slice = self.as_mut_slice();
+        let mut kept = usize::from(!slice.is_empty());
+        for i in 1..slice.len() {
+            let (prev, rest) = slice.split_at_mut(i);
+            if !same_bucket(&mut rest[0], &mut prev[kept - 1]) {
+                slice.swap(kept, i);
+                kept += 1;
+            }
+        }
+        self.truncate(kept);
+    }
+
+    /// Equivalent of [`Vec::dedup_by_key`].
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::array::*;
+    ///
+    /// let mut vec = ArrVecApi::new_full([10, 11, 20, 21, 12]);
+    /// vec.dedup_by_key(|x| *x / 10);
+    /// assert_eq!(vec, [10, 20, 12]);
+    /// ```
+    pub fn dedup_by_key<K: PartialEq>(&mut self, mut key: impl FnMut(&mut T) -> K) {
+        self.dedup_by(|a, b| key(a) == key(b));
+    }
+
+    /// Equivalent of [`Vec::dedup`].
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::array::*;
+    ///
+    /// let mut vec = ArrVecApi::new_full([1, 1, 2, 3, 3, 1]);
+    /// vec.dedup();
+    /// assert_eq!(vec, [1, 2, 3, 1]);
+    /// ```
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b);
+    }
+
+    /// Equivalent of [`Vec::extend_from_slice`].
+    ///
+    /// # Panics
+    /// If the items don't fit into the vector. Nothing is appended in this case.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::array::*;
+    ///
+    /// let mut vec = ArrVecApi::<[_; 4]>::new();
+    /// vec.push(String::from("a"));
+    /// vec.extend_from_slice(&[String::from("b"), String::from("c")]);
+    /// assert_eq!(vec, ["a", "b", "c"]);
+    /// ```
+    #[track_caller]
+    pub fn extend_from_slice(&mut self, other: &[T])
+    where
+        T: Clone,
+    {
+        assert!(
+            other.len() <= self.spare_len(),
+            "Call to `extend_from_slice` on `ArrVecApi` with insufficient capacity"
+        );
+        for item in other {
+            self.push(item.clone());
+        }
+    }
+
+    /// Equivalent of [`Vec::resize`].
+    ///
+    /// # Panics
+    /// If `new_len` exceeds the capacity.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::array::*;
+    ///
+    /// let mut vec = ArrVecApi::<[_; 4]>::new();
+    /// vec.resize(3, 'x');
+    /// assert_eq!(vec, ['x', 'x', 'x']);
+    /// vec.resize(1, 'y');
+    /// assert_eq!(vec, ['x']);
+    /// ```
+    #[track_caller]
+    pub fn resize(&mut self, new_len: usize, value: T)
+    where
+        T: Clone,
+    {
+        let len = self.len();
+        if new_len <= len {
+            self.truncate(new_len);
+            return;
+        }
+        assert!(
+            new_len <= self.capacity(),
+            "Call to `resize` on `ArrVecApi` with insufficient capacity"
+        );
+        for _ in len + 1..new_len {
+            self.push(value.clone());
+        }
+        self.push(value);
+    }
+
+    /// Equivalent of [`Vec::split_off`].
+    ///
+    /// # Panics
+    /// If `at > len`.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::array::*;
+    ///
+    /// let mut vec = ArrVecApi::new_full([1, 2, 3, 4]);
+    /// let tail = vec.split_off(1);
+    /// assert_eq!(vec, [1]);
+    /// assert_eq!(tail, [2, 3, 4]);
+    /// ```
+    #[track_caller]
+    pub const fn split_off(&mut self, at: usize) -> Self {
+        let len = self.len();
+        if at > len {
+            const_fmt::fmt![
+                "`at` split index (is ",
+                at,
+                ") should be <= len (is ",
+                len,
+                ")"
+            ]
+            .panic()
+        }
+
+        let mut other = Self::new();
+        let (_, tail) = self.as_mut_slice().split_at_mut(at);
+        // SAFETY: The items in `at..len` are moved into `other`, which has the same capacity,
+        // so they fit. Afterwards, `self` owns the first `at` items and `other` owns the
+        // first `len - at` items.
+        unsafe {
+            core::ptr::copy_nonoverlapping(
+                tail.as_ptr(),
+                other.spare_capacity_mut().as_mut_ptr().cast::<T>(),
+                len - at,
+            );
+            self.set_len(at);
+            other.set_len(len - at);
+        }
+        other
+    }
+}
@@ -1,3 +1,5 @@
+use core::{cmp::Ordering, hash::Hash};
+
 use crate::array::*;
 
 impl<A: Array, U> PartialEq<[U]> for ArrVecApi<A>
@@ -36,6 +38,115 @@ where
     }
 }
 
+impl<A: Array, B: Array> PartialEq<ArrVecApi<B>> for ArrVecApi<A>
+where
+    A::Item: PartialEq<B::Item>,
+{
+    fn eq(&self, other: &ArrVecApi<B>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<A: Array, B: Array> PartialEq<ArrDeqApi<B>> for ArrVecApi<A>
+where
+    A::Item: PartialEq<B::Item>,
+{
+    fn eq(&self, other: &ArrDeqApi<B>) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<A: Array> Eq for ArrVecApi<A> where A::Item: Eq {}
+
+impl<A: Array> PartialOrd for ArrVecApi<A>
+where
+    A::Item: PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.as_slice().partial_cmp(other.as_slice())
+    }
+}
+
+impl<A: Array> Ord for ArrVecApi<A>
+where
+    A::Item: Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_slice().cmp(other.as_slice())
+    }
+}
+
+impl<A: Array> Hash for ArrVecApi<A>
+where
+    A::Item: Hash,
+{
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state);
+    }
+}
+
+impl<A: Array> Clone for ArrVecApi<A>
+where
+    A::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        self.as_slice().iter().cloned().collect()
+    }
+}
+
+impl<T, A: Array<Item = T>> core::ops::Deref for ArrVecApi<A> {
+    type Target = [T];
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+impl<T, A: Array<Item = T>> core::ops::DerefMut for ArrVecApi<A> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T, A: Array<Item = T>> AsRef<[T]> for ArrVecApi<A> {
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+impl<T, A: Array<Item = T>> AsMut<[T]> for ArrVecApi<A> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+impl<T, A: Array<Item = T>> core::borrow::Borrow<[T]> for ArrVecApi<A> {
+    fn borrow(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+impl<T, A: Array<Item = T>> core::borrow::BorrowMut<[T]> for ArrVecApi<A> {
+    fn borrow_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T, I, A> core::ops::Index<I> for ArrVecApi<A, T>
+where
+    A: Array<Item = T>,
+    [T]: core::ops::Index<I>,
+{
+    type Output = <[T] as core::ops::Index<I>>::Output;
+    fn index(&self, index: I) -> &Self::Output {
+        &self.as_slice()[index]
+    }
+}
+impl<T, I, A> core::ops::IndexMut<I> for ArrVecApi<A, T>
+where
+    A: Array<Item = T>,
+    [T]: core::ops::IndexMut<I>,
+{
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        &mut self.as_mut_slice()[index]
+    }
+}
+
 impl<A: Array> core::fmt::Debug for ArrVecApi<A>
 where
     A::Item: core::fmt::Debug,
@@ -51,7 +162,11 @@ impl<A: Array> Default for ArrVecApi<A> {
     }
 }
 
+/// Panics if the iterator yields more items than fit into the vector.
+///
+/// Use [`ArrVecApi::try_from_iter`] to handle this case instead.
 impl<A: Array<Item = T>, T> FromIterator<T> for ArrVecApi<A> {
+    #[track_caller]
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
         let mut this = Self::new();
         this.extend(iter);
@@ -59,13 +174,25 @@ impl<A: Array<Item = T>, T> FromIterator<T> for ArrVecApi<A> {
     }
 }
 
+/// Panics if the iterator yields more items than fit into the vector.
+///
+/// Use [`ArrVecApi::try_extend`] to handle this case instead.
+///
+/// ```should_panic
+/// use genuint::array::*;
+///
+/// let mut vec = ArrVecApi::<[_; 2]>::new();
+/// vec.extend([1, 2, 3]);
+/// ```
 impl<A: Array<Item = T>, T> Extend<T> for ArrVecApi<A> {
+    #[track_caller]
     fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
-        let mut iter = iter.into_iter();
-        while !self.is_full()
-            && let Some(next) = iter.next()
-        {
-            self.push(next);
+        if let Err(err) = self.try_extend(iter) {
+            panic!(
+                "Call to `extend` on `ArrVecApi` with insufficient capacity, \
+                vector was full after accepting {} items",
+                err.accepted
+            )
         }
     }
 }
@@ -0,0 +1,259 @@
+use core::{iter::FusedIterator, mem::MaybeUninit, ops::RangeBounds, ptr};
+
+use crate::array::{helper::*, *};
+
+use super::ArrVecRepr;
+
+/// Iterator returned by [`ArrVecApi::drain`].
+///
+/// When dropped, the remaining drained items are dropped and the items after the drained range
+/// are moved back to close the gap. If the iterator is leaked, the vector only keeps the items
+/// before the drained range.
+pub struct Drain<'a, A: Array> {
+    /// The length of `vec` is the start of the drained range while this exists.
+    vec: &'a mut ArrVecApi<A>,
+    /// The items in `front..back` are yet to be yielded.
+    front: usize,
+    back: usize,
+    tail_start: usize,
+    tail_len: usize,
+}
+
+impl<A: Array<Item = T>, T> Drain<'_, A> {
+    /// Returns a pointer to the backing array of the vector.
+    const fn buf(&mut self) -> *mut MaybeUninit<T> {
+        // SAFETY: The length is not modified
+        let ArrVecRepr { arr, .. } = unsafe { self.vec.as_mut_repr() };
+        arr.as_mut_slice().as_mut_ptr()
+    }
+
+    /// Returns the items that are yet to be yielded as a slice.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::array::*;
+    ///
+    /// let mut vec = ArrVecApi::new_full([1, 2, 3]);
+    /// let mut drain = vec.drain(..);
+    /// drain.next();
+    /// assert_eq!(drain.as_slice(), [2, 3]);
+    /// ```
+    pub fn as_slice(&self) -> &[T] {
+        let ArrVecRepr { arr, .. } = self.vec.as_repr();
+        let remaining = &arr.as_slice()[self.front..self.back];
+        // SAFETY: The items that are yet to be yielded are valid
+        unsafe { crate::utils::assume_init_slice(remaining) }
+    }
+}
+
+impl<A: Array<Item = T>, T> Iterator for Drain<'_, A> {
+    type Item = T;
+    fn next(&mut self) -> Option<T> {
+        if self.front == self.back {
+            return None;
+        }
+        let idx = self.front;
+        self.front += 1;
+        // SAFETY: The item at `idx` is valid and no longer considered part of the range
+        Some(unsafe { self.buf().add(idx).read().assume_init() })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+impl<A: Array<Item = T>, T> DoubleEndedIterator for Drain<'_, A> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        let idx = self.back;
+        // SAFETY: The item at `idx` is valid and no longer considered part of the range
+        Some(unsafe { self.buf().add(idx).read().assume_init() })
+    }
+}
+impl<A: Array> ExactSizeIterator for Drain<'_, A> {}
+impl<A: Array> FusedIterator for Drain<'_, A> {}
+
+impl<A: Array> Drop for Drain<'_, A> {
+    fn drop(&mut self) {
+        /// Moves the tail back, even if dropping one of the remaining items panics.
+        struct MoveTail<'r, 'a, A: Array>(&'r mut Drain<'a, A>);
+        impl<A: Array> Drop for MoveTail<'_, '_, A> {
+            fn drop(&mut self) {
+                let drain = &mut *self.0;
+                let start = drain.vec.len();
+                let buf = drain.buf();
+                // SAFETY: `start <= tail_start` and the tail is in bounds. The first `start`
+                // items are valid, and the valid tail is moved directly after them.
+                unsafe {
+                    ptr::copy(buf.add(drain.tail_start), buf.add(start), drain.tail_len);
+                    drain.vec.set_len(start + drain.tail_len);
+                }
+            }
+        }
+
+        let guard = MoveTail(self);
+        let remaining = guard.0.front..guard.0.back;
+        guard.0.front = remaining.end;
+        let buf = guard.0.buf();
+        // SAFETY: The remaining items are valid and no longer considered part of the range
+        unsafe {
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
+                buf.add(remaining.start).cast::<A::Item>(),
+                remaining.len(),
+            ));
+        }
+    }
+}
+
+/// Iterator returned by [`ArrVecApi::splice`].
+///
+/// When dropped, the remaining removed items are dropped and the replacement items are
+/// inserted.
+pub struct Splice<'a, A: Array, I: Iterator<Item = A::Item>> {
+    drain: Drain<'a, A>,
+    replace_with: I,
+}
+
+impl<A: Array, I: Iterator<Item = A::Item>> Iterator for Splice<'_, A, I> {
+    type Item = A::Item;
+    fn next(&mut self) -> Option<A::Item> {
+        self.drain.next()
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.drain.size_hint()
+    }
+}
+impl<A: Array, I: Iterator<Item = A::Item>> DoubleEndedIterator for Splice<'_, A, I> {
+    fn next_back(&mut self) -> Option<A::Item> {
+        self.drain.next_back()
+    }
+}
+impl<A: Array, I: Iterator<Item = A::Item>> ExactSizeIterator for Splice<'_, A, I> {}
+
+impl<A: Array, I: Iterator<Item = A::Item>> Drop for Splice<'_, A, I> {
+    fn drop(&mut self) {
+        self.drain.by_ref().for_each(drop);
+
+        let drain = &mut self.drain;
+        while let Some(item) = self.replace_with.next() {
+            let len = drain.vec.len();
+            if len == drain.tail_start {
+                // Make room for at least as many items as the iterator promises
+                let extra = self.replace_with.size_hint().0.saturating_add(1);
+                assert!(
+                    extra <= drain.vec.capacity() - drain.tail_start - drain.tail_len,
+                    "Call to `splice` on `ArrVecApi` with insufficient capacity"
+                );
+                let buf = drain.buf();
+                // SAFETY: The tail is moved back by `extra` places, which stays in bounds
+                unsafe {
+                    ptr::copy(
+                        buf.add(drain.tail_start),
+                        buf.add(drain.tail_start + extra),
+                        drain.tail_len,
+                    );
+                }
+                drain.tail_start += extra;
+            }
+            let buf = drain.buf();
+            // SAFETY: `len < tail_start`, so the item at `len` is not valid and not part of the
+            // tail. We then own `len + 1` valid items.
+            unsafe {
+                buf.add(len).write(MaybeUninit::new(item));
+                drain.vec.set_len(len + 1);
+            }
+        }
+    }
+}
+
+impl<A: Array<Item = T>, T> ArrVecApi<A> {
+    /// Equivalent of [`Vec::drain`].
+    ///
+    /// # Panics
+    /// If the start of the range is greater than its end or if the end is greater than the
+    /// length of the vector.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::array::*;
+    ///
+    /// let mut vec = ArrVecApi::new_full([1, 2, 3, 4, 5]);
+    /// let drained = vec.drain(1..3).collect::<ArrVecApi<[_; 5]>>();
+    /// assert_eq!(drained, [2, 3]);
+    /// assert_eq!(vec, [1, 4, 5]);
+    ///
+    /// // Unyielded items are dropped
+    /// vec.drain(..2);
+    /// assert_eq!(vec, [5]);
+    /// ```
+    ///
+    /// Leaking the iterator leaks the items after the start of the range.
+    /// ```
+    /// use genuint::array::*;
+    ///
+    /// let mut vec = ArrVecApi::new_full([1, 2, 3, 4, 5]);
+    /// core::mem::forget(vec.drain(2..3));
+    /// assert_eq!(vec, [1, 2]);
+    /// ```
+    #[track_caller]
+    pub fn drain(&mut self, range: impl RangeBounds<usize>) -> Drain<'_, A> {
+        let len = self.len();
+        let range = slice_range(range, len);
+        // SAFETY: The first `range.start` items stay valid. The others are now owned by the
+        // `Drain`, which restores the length when dropped.
+        unsafe { self.set_len(range.start) };
+        Drain {
+            vec: self,
+            front: range.start,
+            back: range.end,
+            tail_start: range.end,
+            tail_len: len - range.end,
+        }
+    }
+
+    /// Equivalent of [`Vec::splice`].
+    ///
+    /// The replacement items are inserted when the returned iterator is dropped, so only the
+    /// items that actually fit are taken from `replace_with`.
+    ///
+    /// # Panics
+    /// Like [`drain`](Self::drain). Additionally, dropping the returned iterator panics if the
+    /// replacement items don't fit into the vector.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::array::*;
+    ///
+    /// let mut vec = ArrVecApi::<[_; 6]>::from_iter([1, 2, 3, 4]);
+    /// let removed = vec.splice(1..3, [7, 8, 9]).collect::<ArrVecApi<[_; 2]>>();
+    /// assert_eq!(removed, [2, 3]);
+    /// assert_eq!(vec, [1, 7, 8, 9, 4]);
+    ///
+    /// vec.splice(..4, []);
+    /// assert_eq!(vec, [4]);
+    /// ```
+    ///
+    /// ```should_panic
+    /// use genuint::array::*;
+    ///
+    /// let mut vec = ArrVecApi::new_full([1, 2]);
+    /// vec.splice(..1, [3, 4]);
+    /// ```
+    #[track_caller]
+    pub fn splice<I>(
+        &mut self,
+        range: impl RangeBounds<usize>,
+        replace_with: I,
+    ) -> Splice<'_, A, I::IntoIter>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        Splice {
+            drain: self.drain(range),
+            replace_with: replace_with.into_iter(),
+        }
+    }
+}
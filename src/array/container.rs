@@ -6,6 +6,7 @@ use crate::{
 use crate::{condty, uops, utils};
 use core::marker::PhantomData;
 use core::mem::MaybeUninit;
+use core::ptr::NonNull;
 
 // NOTE: Mutable access to fields and construction of this struct requires a safety comment.
 pub(crate) struct DoubleEndedBuffer<A: Array> {
@@ -115,6 +116,14 @@ pub(crate) struct BigCounter<N: Uint> {
     /// Must be less than or equal to N
     digits: CopyArr<usize, DigitLen<N>>,
 }
+impl<N: Uint> Clone for BigCounter<N> {
+    fn clone(&self) -> Self {
+        // SAFETY: Same value as `self`
+        Self {
+            digits: self.digits,
+        }
+    }
+}
 const fn all_zeros(mut digits: &[usize]) -> bool {
     while let &[ref rest @ .., last] = digits {
         digits = rest;
@@ -168,9 +177,9 @@ impl<N: Uint> BigCounter<N> {
             // SAFETY: This construction ensures self == N
             Self {
                 digits: if uint::is_nonzero::<N>() {
-                    BigCounter::<PopDigit<N>>::max()
-                        .digits
-                        .concat([uint::to_usize_overflowing::<N>().0])
+                    // Little-endian, so the least significant digit goes first
+                    ArrApi::new([uint::to_usize_overflowing::<N>().0])
+                        .concat(BigCounter::<PopDigit<N>>::max().digits)
                         .try_retype()
                         .unwrap()
                 } else {
@@ -207,7 +216,10 @@ pub(crate) struct InstanceCounter<T, N: Uint> {
 }
 impl<T, N: Uint> Drop for InstanceCounter<T, N> {
     fn drop(&mut self) {
-        while self.pop().is_some() {}
+        // Popping up to `N` instances may take very long, so skip it if it would be a noop
+        if const { core::mem::needs_drop::<T>() } {
+            while self.pop().is_some() {}
+        }
     }
 }
 impl<T, N: Uint> InstanceCounter<T, N> {
@@ -355,6 +367,20 @@ pub(crate) struct ArrRefConsumer<'a, T, N: Uint> {
         &'a [T],                // else yield from a slice
     >,
 }
+impl<T, N: Uint> Clone for ArrRefConsumer<'_, T, N> {
+    fn clone(&self) -> Self {
+        let inner = self.inner.as_ref();
+        Self {
+            inner: condty::ctx!(
+                |c| {
+                    let (count, r) = c.unwrap_ok(inner);
+                    c.new_ok((count.clone(), *r))
+                },
+                |c| c.new_err(*c.unwrap_err(inner)),
+            ),
+        }
+    }
+}
 impl<'a, T, N: Uint> ArrRefConsumer<'a, T, N> {
     pub const fn new<A>(arr: &'a A) -> Self
     where
@@ -379,9 +405,9 @@ impl<'a, T, N: Uint> ArrRefConsumer<'a, T, N> {
         condty::ctx!(
             |c| {
                 let (count, r) = c.unwrap_ok(inner);
-                match count.is_zero() {
-                    true => None,
-                    false => Some(r),
+                match count.dec() {
+                    true => Some(*r),
+                    false => None,
                 }
             },
             |c| {
@@ -396,4 +422,161 @@ impl<'a, T, N: Uint> ArrRefConsumer<'a, T, N> {
             }
         )
     }
+    pub const fn pop_back(&mut self) -> Option<&'a T> {
+        let inner = self.inner.as_mut();
+        condty::ctx!(
+            |c| {
+                let (count, r) = c.unwrap_ok(inner);
+                match count.dec() {
+                    true => Some(*r),
+                    false => None,
+                }
+            },
+            |c| {
+                let inner = c.unwrap_err(inner);
+                match inner {
+                    [] => None,
+                    [rest @ .., next] => {
+                        *inner = rest;
+                        Some(next)
+                    }
+                }
+            }
+        )
+    }
+    pub const fn len(&self) -> Option<usize> {
+        let inner = self.inner.as_ref();
+        condty::ctx!(
+            |c| c.unwrap_ok(inner).0.to_usize(), //
+            |c| Some(c.unwrap_err(inner).len()),
+        )
+    }
+}
+
+pub(crate) struct ArrMutConsumer<'a, T, N: Uint> {
+    #[allow(clippy::complexity)]
+    inner: condty::CondResult<
+        PopDigit<N>,                                         // if oversized
+        (BigCounter<N>, NonNull<T>, PhantomData<&'a mut T>), // yield a dangling reference N times
+        &'a mut [T],                                         // else yield from a slice
+    >,
+}
+impl<'a, T, N: Uint> ArrMutConsumer<'a, T, N> {
+    pub const fn new<A>(arr: &'a mut A) -> Self
+    where
+        A: Array<Item = T, Length = N>,
+    {
+        const { arr_impl_ubcheck::<A>() }
+
+        Self {
+            inner: condty::ctx!(
+                |c| c.new_ok((
+                    BigCounter::max(),
+                    NonNull::from_mut(arr).cast(),
+                    PhantomData
+                )),
+                |c| c.new_err(arr_api::unsize_mut(arr)),
+            ),
+        }
+    }
+    /// # Safety
+    /// `ptr` must come from an oversized array, i.e. point to a ZST.
+    const unsafe fn conjure_mut(ptr: NonNull<T>) -> &'a mut T {
+        debug_assert!(size_of::<T>() == 0);
+        // SAFETY: `T` is a ZST, so references to it do not cover any memory and cannot alias.
+        // The array is mutably borrowed for 'a, so handing out references to its items is fine.
+        unsafe { &mut *ptr.as_ptr() }
+    }
+    pub const fn pop_front(&mut self) -> Option<&'a mut T> {
+        let inner = self.inner.as_mut();
+        condty::ctx!(
+            |c| {
+                let (count, ptr, _) = c.unwrap_ok(inner);
+                match count.dec() {
+                    // SAFETY: Oversized arrays only exist for ZSTs
+                    true => Some(unsafe { Self::conjure_mut(*ptr) }),
+                    false => None,
+                }
+            },
+            |c| {
+                let inner = c.unwrap_err(inner);
+                #[allow(clippy::mem_replace_with_default)] // `mem::take` is not const
+                match core::mem::replace(inner, &mut []) {
+                    [] => None,
+                    [next, rest @ ..] => {
+                        *inner = rest;
+                        Some(next)
+                    }
+                }
+            }
+        )
+    }
+    pub const fn pop_back(&mut self) -> Option<&'a mut T> {
+        let inner = self.inner.as_mut();
+        condty::ctx!(
+            |c| {
+                let (count, ptr, _) = c.unwrap_ok(inner);
+                match count.dec() {
+                    // SAFETY: Oversized arrays only exist for ZSTs
+                    true => Some(unsafe { Self::conjure_mut(*ptr) }),
+                    false => None,
+                }
+            },
+            |c| {
+                let inner = c.unwrap_err(inner);
+                #[allow(clippy::mem_replace_with_default)] // `mem::take` is not const
+                match core::mem::replace(inner, &mut []) {
+                    [] => None,
+                    [rest @ .., next] => {
+                        *inner = rest;
+                        Some(next)
+                    }
+                }
+            }
+        )
+    }
+    pub const fn len(&self) -> Option<usize> {
+        let inner = self.inner.as_ref();
+        condty::ctx!(
+            |c| c.unwrap_ok(inner).0.to_usize(), //
+            |c| Some(c.unwrap_err(inner).len()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::consts::PtrWidth;
+
+    /// `usize::MAX + 1`
+    type LargeSize = uint::From<uops::Shl<uint::lit!(1), PtrWidth>>;
+
+    #[test]
+    fn big_counter_max() {
+        assert_eq!(BigCounter::<uint::lit!(1000)>::max().to_usize(), Some(1000));
+
+        let mut counter = BigCounter::<LargeSize>::max();
+        assert_eq!(counter.to_usize(), None);
+        assert!(counter.dec());
+        assert_eq!(counter.to_usize(), Some(usize::MAX));
+    }
+
+    #[test]
+    fn arr_ref_consumer_pop_front_oversized() {
+        let arr = Arr::<(), LargeSize>::of(());
+        let mut items = ArrRefConsumer::new(&arr);
+        assert_eq!(items.pop_front(), Some(&()));
+        let count = condty::ctx!(
+            |c| c.unwrap_ok(items.inner.as_ref()).0.to_usize(),
+            |_| unreachable!(),
+        );
+        assert_eq!(count, Some(usize::MAX));
+    }
+
+    #[test]
+    fn instance_counter_drop_oversized() {
+        // Must not pop every instance, which would take more than `usize::MAX` steps
+        drop(InstanceCounter::<(), LargeSize>::full(Arr::of(())));
+    }
 }
@@ -67,3 +67,58 @@ pub(crate) const fn arr_impl_ubcheck<A: Array>() {
 pub(crate) const fn unsize_raw_mut<A: Array>(ptr: *mut A) -> *mut [A::Item] {
     core::ptr::slice_from_raw_parts_mut(ptr.cast(), arr_len::<A>())
 }
+
+pub(crate) const fn reverse_slice<T>(slice: &mut [T]) {
+    let mut i = 0;
+    while i < slice.len() / 2 {
+        slice.swap(i, slice.len() - i - 1);
+        i += 1;
+    }
+}
+
+/// This is way less performant than <[T]>::rotate_left, which is not const.
+#[track_caller]
+pub(crate) const fn rotate_slice_left<T>(slice: &mut [T], mid: usize) {
+    let (lhs, rhs) = slice.split_at_mut(mid);
+    // EFGHIJKLMN^ABCD
+    reverse_slice(lhs);
+    // NMLKJIHGFE^ABCD
+    reverse_slice(rhs);
+    // NMLKJIHGFE^DBCA
+    reverse_slice(slice);
+    // ABCDEFGHIJ^KLMN
+}
+
+/// Converts `range` to a range of indices into a slice of length `len`.
+///
+/// This is equivalent to the unstable `core::slice::range`.
+#[track_caller]
+pub(crate) fn slice_range(
+    range: impl core::ops::RangeBounds<usize>,
+    len: usize,
+) -> core::ops::Range<usize> {
+    use core::ops::Bound;
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start
+            .checked_add(1)
+            .expect("attempted to index slice from after maximum usize"),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end
+            .checked_add(1)
+            .expect("attempted to index slice up to maximum usize"),
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+    assert!(
+        start <= end,
+        "slice index starts at {start} but ends at {end}"
+    );
+    assert!(
+        end <= len,
+        "range end index {end} out of range for slice of length {len}"
+    );
+    start..end
+}
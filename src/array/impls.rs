@@ -1,9 +1,11 @@
+use core::mem::ManuallyDrop;
+
 use crate::{
     Uint,
     array::{helper::*, *},
     condty::CondResult,
     internals::ArraySealed,
-    uops,
+    uint, uops,
 };
 
 // SAFETY: By definition
@@ -47,9 +49,12 @@ impl<A: Array<Item = B>, B: Array> ArraySealed for ArrFlatten<A> {}
 
 mod base;
 mod cmp;
+mod const_gen;
 mod convert_impl;
 mod core_impl;
-mod iter;
+pub(crate) mod iter;
+mod ops;
+mod sort;
 mod tuple_convert;
 
 impl<T, N: Uint, A> ArrApi<A>
@@ -70,6 +75,43 @@ where
         arr_len::<Self>()
     }
 
+    /// Returns the length that arrays of this type have as a [`u128`], or [`None`] if it
+    /// does not fit.
+    ///
+    /// The length as a type is always available as [`Array::Length`].
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::{array::*, uint};
+    /// assert_eq!(Arr::<(), uint::lit!(1_000)>::length_u128(), Some(1_000));
+    /// ```
+    pub const fn length_u128() -> Option<u128> {
+        uint::to_u128::<N>()
+    }
+
+    /// Returns the decimal representation of the length that arrays of this type have.
+    ///
+    /// This method supports arrays with lengths exceeding [`usize::MAX`] (and [`u128::MAX`]).
+    ///
+    /// # Examples
+    /// ```
+    /// #![recursion_limit = "1024"]
+    /// use genuint::{array::*, uint, uops, consts::PtrWidth};
+    /// type LargeSize = uint::From<uops::Shl<uint::lit!(1), PtrWidth>>;
+    /// type Large = Arr<(), LargeSize>;
+    /// assert_eq!(
+    ///     Large::length_str(),
+    ///     (1u128 << usize::BITS).to_string(),
+    /// );
+    /// assert_eq!(
+    ///     format!("{:?}", Large::of(())),
+    ///     format!("[(); {}]", Large::length_str()),
+    /// );
+    /// ```
+    pub const fn length_str() -> &'static str {
+        uint::to_str::<N>()
+    }
+
     /// Returns the wrapped array of this [`ArrApi`].
     ///
     /// This method is primarily useful when dealing with nested [`ArrApi`]s
@@ -102,6 +144,85 @@ where
         out.assert_full()
     }
 
+    /// Fallible version of [`from_fn`](Self::from_fn).
+    ///
+    /// Items that were already created are dropped if `f` returns an error.
+    ///
+    /// # Errors
+    /// Returns the first error returned by `f`. `f` is not called again after that.
+    ///
+    /// # Panics
+    /// If `Length > usize::MAX`. The generating function is not called in this case.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::{array::*, small::*};
+    /// let arr = Arr::<_, U4>::try_from_fn(|i| u8::try_from(i * 100));
+    /// assert!(arr.is_err());
+    /// let arr = Arr::<_, U3>::try_from_fn(|i| u8::try_from(i * 100));
+    /// assert_eq!(arr, Ok(ArrApi::new([0, 100, 200]).retype()));
+    /// ```
+    ///
+    /// Already created items are dropped on error.
+    /// ```
+    /// use genuint::{array::*, small::*};
+    /// use std::rc::Rc;
+    /// let rc = Rc::new(());
+    /// let arr = Arr::<_, U4>::try_from_fn(|i| if i < 2 { Ok(rc.clone()) } else { Err(i) });
+    /// assert_eq!(arr.err(), Some(2));
+    /// assert_eq!(Rc::strong_count(&rc), 1);
+    /// ```
+    #[track_caller]
+    pub fn try_from_fn<E, F: FnMut(usize) -> Result<T, E>>(mut f: F) -> Result<Self, E> {
+        let _ = Self::length();
+
+        let mut out = ArrVecApi::new();
+        while !out.is_full() {
+            out.push(f(out.len())?);
+        }
+        Ok(out.assert_full())
+    }
+
+    /// Collects an iterator that is expected to yield exactly `Length` items.
+    ///
+    /// Unlike collecting into an [`ArrVecApi`], this reports iterators of the wrong length
+    /// instead of silently truncating them.
+    ///
+    /// # Errors
+    /// If the iterator yields fewer or more than `Length` items. In this case, all items
+    /// taken from the iterator are dropped. At most `Length + 1` items are taken.
+    ///
+    /// # Panics
+    /// If `Length > usize::MAX`. The iterator is not advanced in this case.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::{array::*, small::*};
+    /// assert_eq!(Arr::<_, U3>::from_iter_exact(0..3), Ok(ArrApi::new([0, 1, 2]).retype()));
+    /// assert_eq!(Arr::<_, U3>::from_iter_exact(0..2), Err(FromIterExactError::TooShort(2)));
+    /// assert_eq!(Arr::<_, U3>::from_iter_exact(0..4), Err(FromIterExactError::TooLong));
+    /// ```
+    #[track_caller]
+    pub fn from_iter_exact<I>(iter: I) -> Result<Self, FromIterExactError>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let _ = Self::length();
+
+        let mut iter = iter.into_iter();
+        let mut out = ArrVecApi::new();
+        while !out.is_full() {
+            match iter.next() {
+                Some(item) => out.push(item),
+                None => return Err(FromIterExactError::TooShort(out.len())),
+            }
+        }
+        match iter.next() {
+            Some(_) => Err(FromIterExactError::TooLong),
+            None => Ok(out.assert_full()),
+        }
+    }
+
     /// Converts into an array with the same item and length.
     ///
     /// This method supports arrays with lengths exceeding [`usize::MAX`].
@@ -165,3 +286,36 @@ where
         ArrApi::new(ArrFlatten(self.into_inner()))
     }
 }
+
+impl<T, A, B> ArrApi<ArrConcat<A, B>>
+where
+    A: Array<Item = T>,
+    B: Array<Item = T>,
+{
+    /// Splits the array into the two arrays it was concatenated from.
+    ///
+    /// This is the inverse of [`concat`](ArrApi::concat) and works in `const` contexts, since
+    /// the parts are moved out via [`ArrConcat::manually_drop_parts`].
+    ///
+    /// This method supports arrays with lengths exceeding [`usize::MAX`].
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::{array::*, small::*};
+    /// const fn split_header(packet: Arr<u8, U6>) -> (Arr<u8, U2>, Arr<u8, U4>) {
+    ///     let parts: ArrApi<ArrConcat<Arr<u8, U2>, Arr<u8, U4>>> = packet.retype();
+    ///     let (header, payload) = parts.split_concat();
+    ///     (header.into_inner(), payload.into_inner())
+    /// }
+    /// let (header, payload) = split_header(arr_concat![[0xAB, 4], [1, 2, 3, 4]]);
+    /// assert_eq!(header, [0xAB, 4]);
+    /// assert_eq!(payload, [1, 2, 3, 4]);
+    /// ```
+    pub const fn split_concat(self) -> (ArrApi<A>, ArrApi<B>) {
+        let ArrConcat(a, b) = self.into_inner().manually_drop_parts();
+        (
+            ArrApi::new(ManuallyDrop::into_inner(a)),
+            ArrApi::new(ManuallyDrop::into_inner(b)),
+        )
+    }
+}
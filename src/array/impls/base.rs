@@ -94,6 +94,47 @@ where
     }
 }
 
+impl<T: Copy, N: Uint, A> ArrApi<A>
+where
+    A: Array<Item = T, Length = N>,
+{
+    /// Converts the array to length `M`, either by truncating it or by filling the missing
+    /// items with `fill`.
+    ///
+    /// See [`resize_with`](Self::resize_with) for item types that are not [`Copy`].
+    ///
+    /// # Panics
+    /// If `Length > usize::MAX` or `M > usize::MAX`.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::{array::*, small::*};
+    /// const V1: [u8; 3] = [1, 2, 3];
+    /// const NORMALIZED: Arr<u8, U5> = ArrApi::new(V1).resize(0xFF);
+    /// assert_eq!(NORMALIZED, [1, 2, 3, 0xFF, 0xFF]);
+    /// assert_eq!(ArrApi::new(V1).resize::<U2>(0), [1, 2]);
+    /// ```
+    #[track_caller]
+    pub const fn resize<M: Uint>(self, fill: T) -> Arr<T, M> {
+        let n = arr_len::<A>();
+        let m = arr_len::<Arr<T, M>>();
+
+        let mut out: ArrApi<MaybeUninit<Arr<T, M>>> =
+            ArrApi::new(MaybeUninit::new(self)).retype_uninit();
+        let mut buf = out.as_mut_slice();
+        if n < m {
+            buf = buf.split_at_mut(n).1;
+            while let [first, rest @ ..] = buf {
+                *first = MaybeUninit::new(fill);
+                buf = rest;
+            }
+        }
+        // SAFETY: The first `min(n, m)` items were moved from `self` and the rest were
+        // initialized with `fill`. Forgetting the truncated items is fine since `T: Copy`.
+        unsafe { out.into_inner().assume_init() }
+    }
+}
+
 impl<T, N: Uint, A> ArrApi<A>
 where
     A: Array<Item = MaybeUninit<T>, Length = N>,
@@ -1,3 +1,5 @@
+use core::cmp::Ordering;
+
 use crate::{ToUint, array::*, consts::ConstUsize};
 
 fn partial_eq_impl<A: Array, U>(lhs: &ArrApi<A>, rhs: &[U]) -> bool
@@ -7,6 +9,21 @@ where
     (const { crate::uint::to_usize::<A::Length>().is_some() } && lhs.as_slice() == rhs)
 }
 
+/// Returns the first items of two oversized arrays.
+fn oversized_first_items<'a, A: Array, B: Array>(
+    lhs: &'a ArrApi<A>,
+    rhs: &'a ArrApi<B>,
+) -> (&'a A::Item, &'a B::Item) {
+    debug_assert!(crate::uint::to_usize::<A::Length>().is_none());
+    match (
+        container::ArrRefConsumer::new(lhs).pop_front(),
+        container::ArrRefConsumer::new(rhs).pop_front(),
+    ) {
+        (Some(l), Some(r)) => (l, r),
+        _ => unreachable!("oversized arrays are nonempty"),
+    }
+}
+
 impl<A, U> PartialEq<[U]> for ArrApi<A>
 where
     A: Array,
@@ -35,6 +52,17 @@ where
         partial_eq_impl(self, other)
     }
 }
+impl<A, T, const N: usize> PartialEq<ArrApi<A>> for [T; N]
+where
+    A: Array,
+    T: PartialEq<A::Item>,
+    ConstUsize<N>: ToUint<ToUint = A::Length>,
+{
+    fn eq(&self, other: &ArrApi<A>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+/// [Oversized arrays](crate::array#oversized-arrays) are compared by their first items.
 impl<A, B> PartialEq<ArrApi<B>> for ArrApi<A>
 where
     A: Array,
@@ -47,14 +75,8 @@ where
         } else if const { crate::uint::to_usize::<A::Length>().is_some() } {
             self.as_slice() == other.as_slice()
         } else {
-            let mut lhs = container::ArrRefConsumer::new(self);
-            let mut rhs = container::ArrRefConsumer::new(other);
-            while let (Some(l), Some(r)) = (lhs.pop_front(), rhs.pop_front()) {
-                if l != r {
-                    return false;
-                }
-            }
-            true
+            let (l, r) = oversized_first_items(self, other);
+            l == r
         }
     }
 }
@@ -65,23 +87,54 @@ where
 {
 }
 
+/// [Oversized arrays](crate::array#oversized-arrays) are compared by their first items.
 impl<A> PartialOrd for ArrApi<A>
 where
     A: Array,
     A::Item: PartialOrd,
 {
-    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
-        // FIXME: oversize
-        self.as_slice().partial_cmp(other.as_slice())
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if const { crate::uint::to_usize::<A::Length>().is_some() } {
+            self.as_slice().partial_cmp(other.as_slice())
+        } else {
+            let (l, r) = oversized_first_items(self, other);
+            l.partial_cmp(r)
+        }
     }
 }
+/// [Oversized arrays](crate::array#oversized-arrays) are compared by their first items.
 impl<A> Ord for ArrApi<A>
 where
     A: Array,
     A::Item: Ord,
 {
-    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
-        // FIXME: oversize
-        self.as_slice().cmp(other.as_slice())
+    fn cmp(&self, other: &Self) -> Ordering {
+        if const { crate::uint::to_usize::<A::Length>().is_some() } {
+            self.as_slice().cmp(other.as_slice())
+        } else {
+            let (l, r) = oversized_first_items(self, other);
+            l.cmp(r)
+        }
+    }
+}
+
+impl<A, const N: usize> PartialOrd<[A::Item; N]> for ArrApi<A>
+where
+    A: Array,
+    A::Item: PartialOrd,
+    ConstUsize<N>: ToUint<ToUint = A::Length>,
+{
+    fn partial_cmp(&self, other: &[A::Item; N]) -> Option<Ordering> {
+        self.as_slice().partial_cmp(other.as_slice())
+    }
+}
+impl<A, const N: usize> PartialOrd<ArrApi<A>> for [A::Item; N]
+where
+    A: Array,
+    A::Item: PartialOrd,
+    ConstUsize<N>: ToUint<ToUint = A::Length>,
+{
+    fn partial_cmp(&self, other: &ArrApi<A>) -> Option<Ordering> {
+        self.as_slice().partial_cmp(other.as_slice())
     }
 }
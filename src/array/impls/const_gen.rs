@@ -0,0 +1,309 @@
+use core::marker::PhantomData;
+use core::mem::{ManuallyDrop, MaybeUninit};
+
+use type_const::{Const, value_of};
+
+use crate::{ToUint, Uint, array::*, uint, uops};
+
+/// The index `I` as a [`Const`].
+struct Index<I>(PhantomData<I>);
+impl<I: ToUint> Const for Index<I> {
+    type Type = usize;
+    const VALUE: Self::Type = uint::to_usize::<I>().unwrap();
+}
+
+/// The item generated by `G` at index `I` if `Cond` is nonzero.
+///
+/// `G` is only evaluated if `Cond` is nonzero, so it is never called with an index that is
+/// out of bounds.
+struct Leaf<G, I, Cond>(PhantomData<(G, I, Cond)>);
+impl<G, I, Cond> Const for Leaf<G, I, Cond>
+where
+    G: ConstMap<usize>,
+    I: ToUint,
+    Cond: ToUint,
+{
+    type Type = Option<G::Output>;
+    const VALUE: Self::Type = if uint::is_nonzero::<Cond>() {
+        Some(value_of::<G::Apply<Index<I>>>())
+    } else {
+        None
+    };
+}
+
+/// Pushes the items generated by `G` for the indices `Off..Off + N` onto `out`.
+///
+/// The indices have to be passed as types, so this recurses on the bits of `N`. This
+/// terminates because `PopBit<0>` is `0`.
+const fn fill<G, Off, N, A>(out: &mut ArrVecApi<A>)
+where
+    G: ConstMap<usize>,
+    Off: Uint,
+    N: Uint,
+    A: Array<Item = G::Output>,
+{
+    if uint::is_zero::<N>() {
+        return;
+    }
+    fill::<G, Off, uint::From<uops::PopBit<N>>, A>(out);
+    fill::<G, uint::From<uops::Add<Off, uops::PopBit<N>>>, uint::From<uops::PopBit<N>>, A>(out);
+    if uint::is_nonzero::<uops::LastBit<N>>() {
+        out.push(
+            value_of::<
+                Leaf<
+                    G,
+                    uops::Add<Off, uops::SatSub<N, uint::lit!(1)>>, // the last index
+                    uops::LastBit<N>,
+                >,
+            >()
+            .unwrap(),
+        );
+    }
+}
+
+/// Maps the items of the array `C` using `M`.
+struct MapItems<C, M>(PhantomData<(C, M)>);
+impl<C, M> ConstMap<usize> for MapItems<C, M>
+where
+    C: Const<Type: Array>,
+    M: ConstMap<<C::Type as Array>::Item>,
+{
+    type Output = M::Output;
+    type Apply<X: Const<Type = usize>> = M::Apply<ItemAt<C, X>>;
+}
+
+/// The item of the array `C` at index `X`.
+struct ItemAt<C, X>(PhantomData<(C, X)>);
+impl<C, X> Const for ItemAt<C, X>
+where
+    C: Const<Type: Array>,
+    X: Const<Type = usize>,
+{
+    type Type = <C::Type as Array>::Item;
+    const VALUE: Self::Type = {
+        let arr = ManuallyDrop::new(value_of::<C>());
+        let idx = value_of::<X>();
+        assert!(idx < helper::arr_len::<C::Type>());
+        // SAFETY: `ManuallyDrop` is `repr(transparent)` and arrays consist of `arr_len` items
+        // laid out like a slice, so `idx` is in bounds. The item is moved out of a fresh instance
+        // of the array, whose other items are never dropped.
+        unsafe { (&raw const arr).cast::<Self::Type>().add(idx).read() }
+    };
+}
+
+/// Whether the item of `C` at index `J` goes before the one at index `I` once sorted by `L`.
+///
+/// Ties are broken by the index, which makes this a strict total order.
+struct Before<C, L, I>(PhantomData<(C, L, I)>);
+impl<C, L, I> ConstMap<usize> for Before<C, L, I>
+where
+    C: Const<Type: Array>,
+    L: ConstLess<<C::Type as Array>::Item>,
+    I: Const<Type = usize>,
+{
+    type Output = bool;
+    type Apply<J: Const<Type = usize>> = BeforeAt<C, L, I, J>;
+}
+struct BeforeAt<C, L, I, J>(PhantomData<(C, L, I, J)>);
+impl<C, L, I, J> Const for BeforeAt<C, L, I, J>
+where
+    C: Const<Type: Array>,
+    L: ConstLess<<C::Type as Array>::Item>,
+    I: Const<Type = usize>,
+    J: Const<Type = usize>,
+{
+    type Type = bool;
+    const VALUE: Self::Type = {
+        let i = value_of::<I>();
+        let j = value_of::<J>();
+        if j < i {
+            !value_of::<L::Less<ItemAt<C, I>, ItemAt<C, J>>>()
+        } else if j > i {
+            value_of::<L::Less<ItemAt<C, J>, ItemAt<C, I>>>()
+        } else {
+            false
+        }
+    };
+}
+
+/// The index of each item of `C` once sorted by `L`.
+struct Ranks<C, L>(PhantomData<(C, L)>);
+impl<C, L> ConstMap<usize> for Ranks<C, L>
+where
+    C: Const<Type: Array>,
+    L: ConstLess<<C::Type as Array>::Item>,
+{
+    type Output = usize;
+    type Apply<I: Const<Type = usize>> = RankOf<C, L, I>;
+}
+struct RankOf<C, L, I>(PhantomData<(C, L, I)>);
+impl<C, L, I> Const for RankOf<C, L, I>
+where
+    C: Const<Type: Array>,
+    L: ConstLess<<C::Type as Array>::Item>,
+    I: Const<Type = usize>,
+{
+    type Type = usize;
+    const VALUE: Self::Type = {
+        let before = Arr::<bool, <C::Type as Array>::Length>::from_const_fn::<Before<C, L, I>>();
+        let mut rank = 0;
+        let mut i = 0;
+        while i < before.as_slice().len() {
+            rank += before.as_slice()[i] as usize;
+            i += 1;
+        }
+        core::mem::forget(before);
+        rank
+    };
+}
+
+impl<T, N: Uint, A> ArrApi<A>
+where
+    A: Array<Item = T, Length = N>,
+{
+    /// Creates an array by applying the type-level function `G` to each index.
+    ///
+    /// This is the `const` version of [`from_fn`](Self::from_fn). Since closures cannot be
+    /// called in `const`, the generating function is passed as a [`ConstMap`] instead.
+    /// `G` is only evaluated for indices less than `Length`.
+    ///
+    /// # Panics
+    /// If `Length > usize::MAX`.
+    ///
+    /// # Examples
+    /// A lookup table for CRC-8:
+    /// ```
+    /// use genuint::{array::*, small::*};
+    /// use type_const::{Const, value_of};
+    ///
+    /// const fn crc8(mut crc: u8) -> u8 {
+    ///     let mut i = 0;
+    ///     while i < 8 {
+    ///         crc = if crc & 0x80 != 0 { (crc << 1) ^ 0x07 } else { crc << 1 };
+    ///         i += 1;
+    ///     }
+    ///     crc
+    /// }
+    ///
+    /// struct Crc8;
+    /// struct Crc8Of<X>(X);
+    /// impl<X: Const<Type = usize>> Const for Crc8Of<X> {
+    ///     type Type = u8;
+    ///     const VALUE: u8 = crc8(value_of::<X>() as u8);
+    /// }
+    /// impl ConstMap<usize> for Crc8 {
+    ///     type Output = u8;
+    ///     type Apply<X: Const<Type = usize>> = Crc8Of<X>;
+    /// }
+    ///
+    /// const TABLE: Arr<u8, U256> = ArrApi::from_const_fn::<Crc8>();
+    /// assert_eq!(TABLE[0], 0);
+    /// assert_eq!(TABLE[1], 0x07);
+    /// assert_eq!(TABLE[255], 0xF3);
+    /// ```
+    #[track_caller]
+    pub const fn from_const_fn<G>() -> Self
+    where
+        G: ConstMap<usize, Output = T>,
+    {
+        let _ = Self::length();
+
+        let mut out = ArrVecApi::new();
+        fill::<G, uint::lit!(0), N, _>(&mut out);
+        out.assert_full()
+    }
+
+    /// Creates an array by applying the type-level function `M` to each item of the array `C`.
+    ///
+    /// This is the `const` version of [`map`](Self::map). Since closures cannot be called in
+    /// `const`, the mapping function is passed as a [`ConstMap`] and the source array as a
+    /// [`Const`](type_const::Const).
+    ///
+    /// # Panics
+    /// If `Length > usize::MAX`.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::{array::*, small::*};
+    /// use type_const::{Const, value_of};
+    ///
+    /// struct Primes;
+    /// impl Const for Primes {
+    ///     type Type = Arr<u32, U4>;
+    ///     const VALUE: Self::Type = ArrApi::new([2, 3, 5, 7]).retype();
+    /// }
+    ///
+    /// struct Double;
+    /// struct DoubleOf<X>(X);
+    /// impl<X: Const<Type = u32>> Const for DoubleOf<X> {
+    ///     type Type = u64;
+    ///     const VALUE: u64 = value_of::<X>() as u64 * 2;
+    /// }
+    /// impl ConstMap<u32> for Double {
+    ///     type Output = u64;
+    ///     type Apply<X: Const<Type = u32>> = DoubleOf<X>;
+    /// }
+    ///
+    /// const DOUBLED: Arr<u64, U4> = ArrApi::map_const::<Primes, Double>();
+    /// assert_eq!(DOUBLED, [4, 6, 10, 14]);
+    /// ```
+    #[track_caller]
+    pub const fn map_const<C, M>() -> Self
+    where
+        C: Const<Type: Array<Length = N>>,
+        M: ConstMap<<C::Type as Array>::Item, Output = T>,
+    {
+        Self::from_const_fn::<MapItems<C, M>>()
+    }
+
+    /// Creates an array by sorting the items of the array `C` by `L`.
+    ///
+    /// This is a `const` stable sort. The order is passed as a [`ConstLess`] instead of a
+    /// comparator closure, because closures cannot be called in `const`. For primitive item
+    /// types, [`sort_unstable`](Self::sort_unstable) is simpler and faster to compile.
+    ///
+    /// This is quadratic: unlike the sorting network of [`sort_unstable`](Self::sort_unstable),
+    /// it ranks each item by comparing it with every other item, so compile-time evaluation
+    /// instantiates `L` for all `Length²` pairs. Checking the ranks is quadratic as well. This is
+    /// only suitable for small arrays.
+    ///
+    /// # Panics
+    /// If `Length > usize::MAX` or if `L` is not a strict weak ordering.
+    ///
+    /// # Examples
+    /// See [`ConstLess`].
+    #[track_caller]
+    pub const fn sort_const<C, L>() -> Self
+    where
+        C: Const<Type: Array<Item = T, Length = N>>,
+        L: ConstLess<T>,
+    {
+        let ranks = ManuallyDrop::new(Arr::<usize, N>::from_const_fn::<Ranks<C, L>>());
+        let ranks = const_util::mem::man_drop_ref(&ranks).as_slice();
+        let n = ranks.len();
+        let mut i = 0;
+        while i < n {
+            assert!(ranks[i] < n, "`L` is not a strict weak ordering");
+            let mut j = 0;
+            while j < i {
+                assert!(ranks[i] != ranks[j], "`L` is not a strict weak ordering");
+                j += 1;
+            }
+            i += 1;
+        }
+
+        let src = ManuallyDrop::new(value_of::<C>());
+        let mut dst = MaybeUninit::<Self>::uninit();
+        let src = (&raw const src).cast::<T>();
+        let dst_ptr = dst.as_mut_ptr().cast::<T>();
+        let mut i = 0;
+        while i < n {
+            // SAFETY: `ranks` is a permutation of `0..n`, so each item of `src` is moved to a
+            // distinct item of `dst` and every item of `dst` is initialized.
+            unsafe { core::ptr::copy_nonoverlapping(src.add(i), dst_ptr.add(ranks[i]), 1) }
+            i += 1;
+        }
+        // SAFETY: See above
+        unsafe { dst.assume_init() }
+    }
+}
@@ -1,6 +1,6 @@
 use core::array::TryFromSliceError;
 
-use crate::array::*;
+use crate::{ToUint, array::*, consts::ConstUsize};
 
 impl<T, A> AsRef<[T]> for ArrApi<A>
 where
@@ -35,6 +35,63 @@ where
     }
 }
 
+impl<T, A> core::ops::Deref for ArrApi<A>
+where
+    A: Array<Item = T>,
+{
+    type Target = [T];
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+impl<T, A> core::ops::DerefMut for ArrApi<A>
+where
+    A: Array<Item = T>,
+{
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T, I, A> core::ops::Index<I> for ArrApi<A, T>
+where
+    A: Array<Item = T>,
+    [T]: core::ops::Index<I>,
+{
+    type Output = <[T] as core::ops::Index<I>>::Output;
+    fn index(&self, index: I) -> &Self::Output {
+        &self.as_slice()[index]
+    }
+}
+impl<T, I, A> core::ops::IndexMut<I> for ArrApi<A, T>
+where
+    A: Array<Item = T>,
+    [T]: core::ops::IndexMut<I>,
+{
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        &mut self.as_mut_slice()[index]
+    }
+}
+
+impl<T, A, const N: usize> From<[T; N]> for ArrApi<A>
+where
+    A: Array<Item = T>,
+    ConstUsize<N>: ToUint<ToUint = A::Length>,
+{
+    fn from(value: [T; N]) -> Self {
+        arr_api::retype(value)
+    }
+}
+impl<T, A, const N: usize> From<ArrApi<A>> for [T; N]
+where
+    A: Array<Item = T>,
+    ConstUsize<N>: ToUint<ToUint = A::Length>,
+{
+    fn from(value: ArrApi<A>) -> Self {
+        arr_api::retype(value)
+    }
+}
+
 fn try_from_slice_error() -> TryFromSliceError {
     enum Never {}
     const EMPTY: &[Never] = &[];
@@ -1,4 +1,8 @@
-use crate::{Uint, array::*, uint};
+use core::mem::{ManuallyDrop, MaybeUninit};
+
+use crate::{Uint, array::*, uint, uops};
+
+use super::iter::{Iter, IterMut, Windows};
 
 impl<T, N: Uint, A> ArrApi<A>
 where
@@ -24,6 +28,360 @@ where
         arr_api::unsize_mut(self)
     }
 
+    /// Returns an iterator over references to the items of the array.
+    ///
+    /// Unlike `self.as_slice().iter()`, this method supports arrays with lengths exceeding
+    /// [`usize::MAX`].
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::{array::*, small::*};
+    /// let arr = Arr::<_, U4>::from_fn(|i| i * i);
+    /// assert!(arr.iter().copied().eq([0, 1, 4, 9]));
+    /// assert_eq!(arr.iter().next_back(), Some(&9));
+    ///
+    /// let mut iter = arr.iter();
+    /// iter.next();
+    /// assert_eq!(iter.len(), 3);
+    /// assert!(iter.clone().eq(&arr[1..]));
+    /// ```
+    ///
+    /// Iterating an oversized array of `()`
+    /// ```
+    /// #![recursion_limit = "1024"]
+    /// use genuint::{array::*, uint, uops, consts::PtrWidth};
+    /// type LargeSize = uint::From<uops::Shl<uint::lit!(1), PtrWidth>>;
+    /// let arr = Arr::<_, LargeSize>::of(());
+    /// let mut iter = arr.iter();
+    /// assert_eq!(iter.size_hint(), (usize::MAX, None));
+    /// assert_eq!(iter.next(), Some(&()));
+    /// assert_eq!(iter.size_hint(), (usize::MAX, Some(usize::MAX)));
+    /// assert_eq!(iter.len(), usize::MAX);
+    /// ```
+    pub const fn iter(&self) -> Iter<'_, T, N> {
+        Iter {
+            items: container::ArrRefConsumer::new(self),
+        }
+    }
+
+    /// Returns an iterator over mutable references to the items of the array.
+    ///
+    /// Unlike `self.as_mut_slice().iter_mut()`, this method supports arrays with lengths
+    /// exceeding [`usize::MAX`].
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::{array::*, small::*};
+    /// let mut arr = Arr::<_, U4>::from_fn(|i| i);
+    /// for item in &mut arr {
+    ///     *item *= 2;
+    /// }
+    /// assert_eq!(arr, [0, 2, 4, 6]);
+    /// assert_eq!(arr.iter_mut().len(), 4);
+    /// ```
+    pub const fn iter_mut(&mut self) -> IterMut<'_, T, N> {
+        IterMut {
+            items: container::ArrMutConsumer::new(self),
+        }
+    }
+
+    /// Equivalent of [`<[T]>::swap`](slice::swap), but `const`.
+    ///
+    /// # Panics
+    /// If `a` or `b` are out of bounds.
+    #[track_caller]
+    pub const fn swap(&mut self, a: usize, b: usize) {
+        self.as_mut_slice().swap(a, b);
+    }
+
+    /// Equivalent of [`<[T]>::reverse`](slice::reverse), but `const`.
+    ///
+    /// # Panics
+    /// If `Length > usize::MAX`.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::array::*;
+    /// const REVERSED: [i32; 3] = {
+    ///     let mut arr = ArrApi::new([1, 2, 3]);
+    ///     arr.reverse();
+    ///     arr.into_inner()
+    /// };
+    /// assert_eq!(REVERSED, [3, 2, 1]);
+    /// ```
+    #[track_caller]
+    pub const fn reverse(&mut self) {
+        helper::reverse_slice(self.as_mut_slice());
+    }
+
+    /// Equivalent of [`<[T]>::rotate_left`](slice::rotate_left), but `const`.
+    ///
+    /// # Panics
+    /// If `mid > Length`.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::array::*;
+    /// let mut arr = ArrApi::new([1, 2, 3, 4, 5]);
+    /// arr.rotate_left(2);
+    /// assert_eq!(arr, [3, 4, 5, 1, 2]);
+    /// ```
+    #[track_caller]
+    pub const fn rotate_left(&mut self, mid: usize) {
+        helper::rotate_slice_left(self.as_mut_slice(), mid);
+    }
+
+    /// Equivalent of [`<[T]>::rotate_right`](slice::rotate_right), but `const`.
+    ///
+    /// # Panics
+    /// If `k > Length`.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::array::*;
+    /// let mut arr = ArrApi::new([1, 2, 3, 4, 5]);
+    /// arr.rotate_right(2);
+    /// assert_eq!(arr, [4, 5, 1, 2, 3]);
+    /// ```
+    #[track_caller]
+    pub const fn rotate_right(&mut self, k: usize) {
+        let slice = self.as_mut_slice();
+        assert!(
+            k <= slice.len(),
+            "rotation amount exceeds the length of the array"
+        );
+        helper::rotate_slice_left(slice, slice.len() - k);
+    }
+
+    /// Rotates the array to the left by `K % Length` items.
+    ///
+    /// Unlike [`rotate_left`](Self::rotate_left), the rotation amount is known at compile time
+    /// and can never be out of bounds.
+    ///
+    /// # Panics
+    /// If `Length > usize::MAX`.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::{array::*, small::*};
+    /// const ROTATED: [i32; 4] = ArrApi::new([1, 2, 3, 4]).rotated_left::<U5>().into_inner();
+    /// assert_eq!(ROTATED, [2, 3, 4, 1]);
+    /// ```
+    #[track_caller]
+    pub const fn rotated_left<K: Uint>(mut self) -> Self {
+        let _ = Self::length();
+
+        let mid = const { uint::to_usize::<uops::Rem<K, uops::Max<N, uint::lit!(1)>>>() };
+        match mid {
+            Some(mid) => self.rotate_left(mid),
+            None => unreachable!(),
+        }
+        self
+    }
+
+    /// Rotates the array to the right by `K % Length` items.
+    ///
+    /// Unlike [`rotate_right`](Self::rotate_right), the rotation amount is known at compile
+    /// time and can never be out of bounds.
+    ///
+    /// # Panics
+    /// If `Length > usize::MAX`.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::{array::*, small::*};
+    /// let arr = ArrApi::new([1, 2, 3, 4]);
+    /// assert_eq!(arr.rotated_right::<U1>(), [4, 1, 2, 3]);
+    /// assert_eq!(arr.rotated_right::<U4>(), arr);
+    /// ```
+    #[track_caller]
+    pub const fn rotated_right<K: Uint>(mut self) -> Self {
+        let _ = Self::length();
+
+        let k = const { uint::to_usize::<uops::Rem<K, uops::Max<N, uint::lit!(1)>>>() };
+        match k {
+            Some(k) => self.rotate_right(k),
+            None => unreachable!(),
+        }
+        self
+    }
+
+    /// Converts the array to length `M`, either by truncating it or by filling the missing
+    /// items by calling `f`.
+    ///
+    /// Truncated items are dropped.
+    ///
+    /// # Panics
+    /// If `M > usize::MAX`.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::{array::*, small::*};
+    /// let arr = ArrApi::new([String::from("a")]);
+    /// let resized: Arr<String, U3> = arr.resize_with(String::new);
+    /// assert_eq!(resized, ["a", "", ""]);
+    /// ```
+    #[track_caller]
+    pub fn resize_with<M: Uint>(self, mut f: impl FnMut() -> T) -> Arr<T, M> {
+        let mut items = self.into_iter();
+        ArrApi::from_fn(|_| items.next().unwrap_or_else(&mut f))
+    }
+
+    /// Extends the array to length `M` by appending default values.
+    ///
+    /// Using an `M` less than `Length` is a compile error. Use [`truncate`](Self::truncate)
+    /// to shorten arrays instead.
+    ///
+    /// # Panics
+    /// If `M > usize::MAX`.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::{array::*, small::*};
+    /// let arr = ArrApi::new([1, 2]);
+    /// assert_eq!(arr.pad_to::<U4>(), [1, 2, 0, 0]);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use genuint::{array::*, small::*};
+    /// let arr = ArrApi::new([1, 2]);
+    /// let _ = arr.pad_to::<U1>();
+    /// ```
+    #[track_caller]
+    pub fn pad_to<M: Uint>(self) -> Arr<T, M>
+    where
+        T: Default,
+    {
+        const {
+            assert!(
+                uint::is_nonzero::<uops::Le<N, M>>(),
+                "Cannot pad an array to a smaller length"
+            )
+        };
+        self.resize_with(T::default)
+    }
+
+    /// Shortens the array to its first `M` items and drops the rest.
+    ///
+    /// Using an `M` greater than `Length` is a compile error.
+    ///
+    /// This cannot be `const`, since the rest of the items may need to be dropped. In `const`
+    /// contexts, [`retype`](Self::retype) the array into an [`ArrConcat`] and use
+    /// [`manually_drop_parts`](ArrConcat::manually_drop_parts) instead.
+    ///
+    /// # Panics
+    /// If `M > usize::MAX`.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::{array::*, small::*};
+    /// let arr = ArrApi::new([1, 2, 3]);
+    /// assert_eq!(arr.truncate::<U2>(), [1, 2]);
+    ///
+    /// let names = ArrApi::new([String::from("a"), String::from("b")]);
+    /// assert_eq!(names.truncate::<U1>(), ["a"]);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use genuint::{array::*, small::*};
+    /// let arr = ArrApi::new([1, 2, 3]);
+    /// let _ = arr.truncate::<U4>();
+    /// ```
+    #[track_caller]
+    pub fn truncate<M: Uint>(self) -> Arr<T, M> {
+        const {
+            assert!(
+                uint::is_nonzero::<uops::Le<M, N>>(),
+                "Cannot truncate an array to a greater length"
+            )
+        };
+        let ArrConcat(head, tail) = self.try_retype::<TruncateParts<T, N, M>>().unwrap();
+        drop(tail);
+        head
+    }
+
+    /// Returns an iterator over all contiguous windows of length `W`, as arrays.
+    ///
+    /// Unlike [`<[T]>::windows`](slice::windows), the length of the windows is part of their
+    /// type. The slice method is still available through [`Deref`](core::ops::Deref).
+    ///
+    /// Using a `W` of zero or greater than `Length` is a compile error.
+    ///
+    /// # Panics
+    /// If `Length > usize::MAX`.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::{array::*, small::*};
+    /// let samples = ArrApi::new([1, 2, 3, 4, 5]);
+    /// let taps = ArrApi::new([1, 0, -1]);
+    /// let filtered: Vec<i32> = samples.iter_windows::<U3>().map(|w| w.dot(taps)).collect();
+    /// assert_eq!(filtered, [-2, -2, -2]);
+    /// assert_eq!(samples.windows(4).count(), 2);
+    /// ```
+    ///
+    /// The number of windows is exact, even for a ZST array of length [`usize::MAX`].
+    /// ```
+    /// #![recursion_limit = "1024"]
+    /// use genuint::{array::*, consts::UsizeMax, small::*};
+    /// let arr = Arr::<(), UsizeMax>::of(());
+    /// assert_eq!(arr.iter_windows::<U1>().len(), usize::MAX);
+    /// assert_eq!(arr.iter_windows::<U2>().size_hint(), (usize::MAX - 1, Some(usize::MAX - 1)));
+    /// ```
+    ///
+    /// Windows can't be longer than the array.
+    /// ```compile_fail
+    /// use genuint::{array::*, small::*};
+    /// let arr = ArrApi::new([1, 2, 3]);
+    /// let _ = arr.iter_windows::<U4>();
+    /// ```
+    #[track_caller]
+    pub const fn iter_windows<W: Uint>(&self) -> Windows<'_, T, W> {
+        const { assert_valid_window::<N, W>() };
+        Windows {
+            slice: self.as_slice(),
+            _width: core::marker::PhantomData,
+        }
+    }
+
+    /// Returns an array of all contiguous windows of length `W`.
+    ///
+    /// Using a `W` of zero or greater than `Length` is a compile error.
+    ///
+    /// # Panics
+    /// If `Length > usize::MAX`.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::{array::*, small::*};
+    /// let arr = ArrApi::new([1, 2, 3, 4]);
+    /// let windows = arr.array_windows::<U2>();
+    /// assert_eq!(windows.len(), 3);
+    /// assert_eq!(windows, [&[1, 2], &[2, 3], &[3, 4]]);
+    /// ```
+    ///
+    /// Windows can't be empty.
+    /// ```compile_fail
+    /// use genuint::{array::*, small::*};
+    /// let arr = ArrApi::new([1, 2, 3]);
+    /// let _ = arr.array_windows::<U0>();
+    /// ```
+    #[track_caller]
+    pub const fn array_windows<W: Uint>(&self) -> Arr<&Arr<T, W>, WindowCount<N, W>> {
+        const { assert_valid_window::<N, W>() };
+        let width = ArrApi::<Arr<T, W>>::length();
+        let mut out = ArrVec::new();
+        let mut this = self.as_slice();
+        while this.len() >= width {
+            match arr_api::try_from_ref_slice(this.split_at(width).0) {
+                Some(window) => out.push(window),
+                None => unreachable!(),
+            }
+            this = this.split_at(1).1;
+        }
+        out.assert_full()
+    }
+
     /// Equivalent of [`<[T; N]>::each_ref`](array::each_ref).
     ///
     /// Note that this method does not compile for `Length > usize::MAX` because the returned
@@ -39,6 +397,9 @@ where
     }
 
     /// Equivalent of [`<[T; N]>::each_mut`](array::each_mut).
+    ///
+    /// Note that this method does not compile for `Length > usize::MAX` because the returned
+    /// array will be too large for the architecture.
     pub const fn each_mut(&mut self) -> ArrApi<impl Array<Item = &mut T, Length = N>> {
         let mut out = ArrVec::new();
         let mut this = self.as_mut_slice();
@@ -63,6 +424,223 @@ where
         // SAFETY: `src` is empty, so this is full
         unsafe { dst.into_full_unchecked() }
     }
+
+    /// Fallible version of [`map`](Self::map).
+    ///
+    /// Items that were already mapped, as well as the remaining items of `self`, are
+    /// dropped if `f` returns an error.
+    ///
+    /// # Errors
+    /// Returns the first error returned by `f`. `f` is not called again after that.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::{array::*, small::*};
+    /// let arr = ArrApi::new(["1", "2", "3"]);
+    /// let parsed: Arr<i32, U3> = arr.try_map(str::parse).unwrap();
+    /// assert_eq!(parsed, [1, 2, 3]);
+    /// let arr = ArrApi::new(["1", "x", "3"]);
+    /// assert!(arr.try_map::<Arr<i32, U3>, _>(str::parse).is_err());
+    /// ```
+    pub fn try_map<B, E>(self, mut f: impl FnMut(T) -> Result<B::Item, E>) -> Result<ArrApi<B>, E>
+    where
+        B: Array<Length = N>,
+    {
+        let mut src = container::ArrConsumer::new(self);
+        let mut dst = container::ArrBuilder::new();
+        while let Some(item) = src.pop_front() {
+            let mapped = f(item)?;
+            // SAFETY: `src` only returns up to `Length` items
+            unsafe { dst.push_unchecked(mapped) }
+        }
+        // SAFETY: `src` is empty, so this is full
+        Ok(unsafe { dst.into_full_unchecked() })
+    }
+
+    /// Combines the items of two arrays of the same length into an array of pairs.
+    ///
+    /// Unlike [`Iterator::zip`], the lengths are checked at compile time.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::{array::*, small::*};
+    /// let lhs = Arr::<_, U3>::from_fn(|i| i);
+    /// let rhs = Arr::<_, U3>::from_fn(|i| i * i);
+    /// assert_eq!(lhs.zip(rhs), [(0, 0), (1, 1), (2, 4)]);
+    /// ```
+    ///
+    /// Arrays of different lengths cannot be zipped.
+    /// ```compile_fail
+    /// use genuint::{array::*, small::*};
+    /// let lhs = Arr::<_, U3>::from_fn(|i| i);
+    /// let rhs = Arr::<_, U4>::from_fn(|i| i);
+    /// let _ = lhs.zip(rhs);
+    /// ```
+    pub fn zip<B>(self, other: B) -> Arr<(T, B::Item), N>
+    where
+        B: Array<Length = N>,
+    {
+        let mut lhs = container::ArrConsumer::new(self);
+        let mut rhs = container::ArrConsumer::new(other);
+        let mut dst = container::ArrBuilder::new();
+        while let (Some(l), Some(r)) = (lhs.pop_front(), rhs.pop_front()) {
+            // SAFETY: `lhs` and `rhs` only return up to `Length` items
+            unsafe { dst.push_unchecked((l, r)) }
+        }
+        // SAFETY: `lhs` and `rhs` have the same length and are empty, so this is full
+        unsafe { dst.into_full_unchecked() }
+    }
+
+    /// Pairs each item with its index.
+    ///
+    /// # Panics
+    /// If `Length > usize::MAX`.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::array::*;
+    /// let arr = ArrApi::new(['a', 'b', 'c']);
+    /// assert_eq!(arr.enumerate(), [(0, 'a'), (1, 'b'), (2, 'c')]);
+    /// ```
+    #[track_caller]
+    pub fn enumerate(self) -> Arr<(usize, T), N> {
+        let _ = Self::length();
+
+        let mut i = 0;
+        self.map(|item| {
+            let idx = i;
+            i += 1;
+            (idx, item)
+        })
+    }
+}
+
+impl<U, V, N: Uint, A> ArrApi<A>
+where
+    A: Array<Item = (U, V), Length = N>,
+{
+    /// Splits an array of pairs into a pair of arrays.
+    ///
+    /// This is the inverse of [`ArrApi::zip`].
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::array::*;
+    /// let arr = ArrApi::new([(1, 'a'), (2, 'b')]);
+    /// let (nums, chars) = arr.unzip();
+    /// assert_eq!(nums, [1, 2]);
+    /// assert_eq!(chars, ['a', 'b']);
+    /// ```
+    pub fn unzip(self) -> (Arr<U, N>, Arr<V, N>) {
+        let mut src = container::ArrConsumer::new(self);
+        let mut lhs = container::ArrBuilder::new();
+        let mut rhs = container::ArrBuilder::new();
+        while let Some((l, r)) = src.pop_front() {
+            // SAFETY: `src` only returns up to `Length` items
+            unsafe {
+                lhs.push_unchecked(l);
+                rhs.push_unchecked(r);
+            }
+        }
+        // SAFETY: `src` is empty, so these are full
+        unsafe { (lhs.into_full_unchecked(), rhs.into_full_unchecked()) }
+    }
+}
+
+impl<T, N: Uint, M: Uint, A, B> ArrApi<A>
+where
+    A: Array<Item = B, Length = N>,
+    B: Array<Item = T, Length = M>,
+{
+    /// Swaps the two dimensions of an array of arrays.
+    ///
+    /// This works in `const` for any item type, since the items are only moved.
+    ///
+    /// # Panics
+    /// If `N > usize::MAX` or `M > usize::MAX`.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::{array::*, small::*};
+    /// const SOA: [[i32; 3]; 2] = [[1, 2, 3], [4, 5, 6]];
+    /// const AOS: Arr<Arr<i32, U2>, U3> = ArrApi::new(SOA).transpose();
+    /// assert_eq!(AOS, [[1, 4], [2, 5], [3, 6]]);
+    ///
+    /// // Items don't need to be `Copy`
+    /// let names = ArrApi::new([["a".to_string(), "b".to_string()]]);
+    /// assert_eq!(names.transpose(), [["a"], ["b"]]);
+    /// ```
+    #[track_caller]
+    pub const fn transpose(self) -> Arr<Arr<T, N>, M> {
+        let n = Self::length();
+        let m = ArrApi::<B>::length();
+
+        let src = ManuallyDrop::new(self);
+        let mut dst = MaybeUninit::<Arr<Arr<T, N>, M>>::uninit();
+        if size_of::<T>() != 0 {
+            let src = (&raw const src).cast::<T>();
+            let dst = dst.as_mut_ptr().cast::<T>();
+            let mut i = 0;
+            while i < n {
+                let mut j = 0;
+                while j < m {
+                    // SAFETY: Both arrays are laid out like `n * m` items, so both indices are in
+                    // bounds. Each item of `src` is moved to exactly one item of `dst` and `src`
+                    // is never dropped.
+                    unsafe {
+                        core::ptr::copy_nonoverlapping(src.add(i * m + j), dst.add(j * n + i), 1)
+                    }
+                    j += 1;
+                }
+                i += 1;
+            }
+        }
+        // SAFETY: All `n * m` items were moved into `dst`. If `T` is zero-sized, there is nothing
+        // to initialize and `T` is inhabited if there are any items.
+        unsafe { dst.assume_init() }
+    }
+}
+
+impl<T, N: Uint, A, B> ArrApi<A>
+where
+    A: Array<Item = B, Length = N>,
+    B: Array<Item = T, Length = N>,
+{
+    /// Swaps the two dimensions of a square array of arrays in place.
+    ///
+    /// # Panics
+    /// If `N > usize::MAX`.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::array::*;
+    /// const fn transposed(arr: [[u8; 2]; 2]) -> [[u8; 2]; 2] {
+    ///     let mut arr = ArrApi::new(arr);
+    ///     arr.transpose_in_place();
+    ///     arr.into_inner()
+    /// }
+    /// assert_eq!(transposed([[1, 2], [3, 4]]), [[1, 3], [2, 4]]);
+    /// ```
+    #[track_caller]
+    pub const fn transpose_in_place(&mut self) {
+        let n = Self::length();
+        if size_of::<T>() == 0 {
+            return;
+        }
+
+        let ptr = (&raw mut *self).cast::<T>();
+        let mut i = 0;
+        while i < n {
+            let mut j = i + 1;
+            while j < n {
+                // SAFETY: The array is laid out like `n * n` items, so both indices are in
+                // bounds. They are distinct because `i != j`.
+                unsafe { core::ptr::swap(ptr.add(i * n + j), ptr.add(j * n + i)) }
+                j += 1;
+            }
+            i += 1;
+        }
+    }
 }
 
 impl<A, T, N: Uint> Clone for ArrApi<A>
@@ -96,16 +674,20 @@ where
 const _: () = {
     use core::fmt::{self, Debug};
 
+    /// [Oversized arrays](crate::array#oversized-arrays) are printed as `[first; len]`.
     impl<A, T, N: Uint> Debug for ArrApi<A>
     where
         A: Array<Item = T, Length = N>,
         T: Debug,
     {
         fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-            if uint::to_usize::<A::Length>().is_some() {
+            if const { uint::to_usize::<A::Length>().is_some() } {
                 write!(f, "{:?}", self.as_slice())
             } else {
-                write!(f, "[...]")
+                let Some(first) = container::ArrRefConsumer::new(self).pop_front() else {
+                    unreachable!("oversized arrays are nonempty")
+                };
+                write!(f, "[{first:?}; {}]", Self::length_str())
             }
         }
     }
@@ -114,6 +696,7 @@ const _: () = {
 const _: () = {
     use core::hash::{Hash, Hasher};
 
+    /// [Oversized arrays](crate::array#oversized-arrays) are hashed by their first item.
     impl<A> Hash for ArrApi<A>
     where
         A: Array<Item: Hash>,
@@ -122,18 +705,24 @@ const _: () = {
             if const { uint::to_usize::<A::Length>().is_some() } {
                 self.as_slice().hash(state)
             } else {
-                fn hash_zst<A, H>(a: &A, h: &mut H)
-                where
-                    A: Array<Item: Hash>,
-                    H: Hasher,
-                {
-                    let mut src = container::ArrRefConsumer::new(a);
-                    while let Some(item) = src.pop_front() {
-                        item.hash(h)
-                    }
-                }
-                hash_zst(self, state);
+                let Some(first) = container::ArrRefConsumer::new(self).pop_front() else {
+                    unreachable!("oversized arrays are nonempty")
+                };
+                first.hash(state)
             }
         }
     }
 };
+
+/// The first `M` items followed by the remaining `N - M`
+type TruncateParts<T, N, M> = ArrConcat<Arr<T, M>, Arr<T, uint::From<uops::SatSub<N, M>>>>;
+
+/// `N - W + 1`
+type WindowCount<N, W> = uint::From<uops::Add<uops::SatSub<N, W>, uint::lit!(1)>>;
+
+const fn assert_valid_window<N: Uint, W: Uint>() {
+    assert!(
+        uint::is_nonzero::<W>() && uint::is_nonzero::<uops::Le<W, N>>(),
+        "Window length must be nonzero and at most the array length"
+    );
+}
@@ -54,6 +54,139 @@ impl<A: Array> IntoIterator for ArrVecApi<A> {
     }
 }
 
+const ITER_LEN_OVERFLOW: &str = "Iterator length exceeds the maximum value for a usize";
+
+/// Iterator returned by [`ArrApi::iter`].
+pub struct Iter<'a, T, N: Uint> {
+    pub(crate) items: ArrRefConsumer<'a, T, N>,
+}
+impl<'a, T, N: Uint> Iterator for Iter<'a, T, N> {
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.items.pop_front()
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        #[allow(clippy::option_if_let_else)]
+        match self.items.len() {
+            Some(len) => (len, Some(len)),
+            None => (usize::MAX, None),
+        }
+    }
+}
+impl<T, N: Uint> DoubleEndedIterator for Iter<'_, T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.items.pop_back()
+    }
+}
+/// # Panics
+/// [`len`](ExactSizeIterator::len) panics if more than `usize::MAX` items are left, which is
+/// only possible for [oversized arrays](crate::array#oversized-arrays).
+impl<T, N: Uint> ExactSizeIterator for Iter<'_, T, N> {
+    fn len(&self) -> usize {
+        self.items.len().expect(ITER_LEN_OVERFLOW)
+    }
+}
+impl<T, N: Uint> core::iter::FusedIterator for Iter<'_, T, N> {}
+impl<T, N: Uint> Clone for Iter<'_, T, N> {
+    fn clone(&self) -> Self {
+        Self {
+            items: self.items.clone(),
+        }
+    }
+}
+
+/// Iterator returned by [`ArrApi::iter_windows`].
+pub struct Windows<'a, T, W: Uint> {
+    pub(crate) slice: &'a [T],
+    pub(crate) _width: core::marker::PhantomData<W>,
+}
+impl<T, W: Uint> Windows<'_, T, W> {
+    const WIDTH: usize = ArrApi::<Arr<T, W>>::length();
+}
+impl<'a, T, W: Uint> Iterator for Windows<'a, T, W> {
+    type Item = &'a Arr<T, W>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let window = crate::array::arr_api::try_from_ref_slice(self.slice.get(..Self::WIDTH)?)?;
+        self.slice = &self.slice[1..];
+        Some(window)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self
+            .slice
+            .len()
+            .checked_sub(Self::WIDTH)
+            .map_or(0, |n| n + 1);
+        (len, Some(len))
+    }
+}
+impl<T, W: Uint> DoubleEndedIterator for Windows<'_, T, W> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let start = self.slice.len().checked_sub(Self::WIDTH)?;
+        let window = crate::array::arr_api::try_from_ref_slice(&self.slice[start..])?;
+        self.slice = &self.slice[..self.slice.len() - 1];
+        Some(window)
+    }
+}
+impl<T, W: Uint> ExactSizeIterator for Windows<'_, T, W> {}
+impl<T, W: Uint> core::iter::FusedIterator for Windows<'_, T, W> {}
+impl<T, W: Uint> Clone for Windows<'_, T, W> {
+    fn clone(&self) -> Self {
+        Self {
+            slice: self.slice,
+            _width: core::marker::PhantomData,
+        }
+    }
+}
+
+/// Iterator returned by [`ArrApi::iter_mut`].
+pub struct IterMut<'a, T, N: Uint> {
+    pub(crate) items: ArrMutConsumer<'a, T, N>,
+}
+impl<'a, T, N: Uint> Iterator for IterMut<'a, T, N> {
+    type Item = &'a mut T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.items.pop_front()
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        #[allow(clippy::option_if_let_else)]
+        match self.items.len() {
+            Some(len) => (len, Some(len)),
+            None => (usize::MAX, None),
+        }
+    }
+}
+impl<T, N: Uint> DoubleEndedIterator for IterMut<'_, T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.items.pop_back()
+    }
+}
+/// # Panics
+/// [`len`](ExactSizeIterator::len) panics if more than `usize::MAX` items are left, which is
+/// only possible for [oversized arrays](crate::array#oversized-arrays).
+impl<T, N: Uint> ExactSizeIterator for IterMut<'_, T, N> {
+    fn len(&self) -> usize {
+        self.items.len().expect(ITER_LEN_OVERFLOW)
+    }
+}
+impl<T, N: Uint> core::iter::FusedIterator for IterMut<'_, T, N> {}
+
+impl<'a, A: Array> IntoIterator for &'a ArrApi<A> {
+    type Item = &'a A::Item;
+    type IntoIter = Iter<'a, A::Item, A::Length>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+impl<'a, A: Array> IntoIterator for &'a mut ArrApi<A> {
+    type Item = &'a mut A::Item;
+    type IntoIter = IterMut<'a, A::Item, A::Length>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
 pub struct IntoIterDeq<A: Array> {
     pub(crate) deq: ArrDeqApi<A>,
 }
@@ -84,3 +217,90 @@ impl<A: Array> IntoIterator for ArrDeqApi<A> {
         Self::IntoIter { deq: self }
     }
 }
+
+/// Iterator over references to the items of an [`ArrDeqApi`].
+pub struct IterDeq<'a, T> {
+    pub(crate) items: core::iter::Chain<core::slice::Iter<'a, T>, core::slice::Iter<'a, T>>,
+}
+impl<'a, T> Iterator for IterDeq<'a, T> {
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.items.next()
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.items.size_hint()
+    }
+}
+impl<T> DoubleEndedIterator for IterDeq<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.items.next_back()
+    }
+}
+impl<T> ExactSizeIterator for IterDeq<'_, T> {}
+impl<T> Clone for IterDeq<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            items: self.items.clone(),
+        }
+    }
+}
+
+/// Iterator over mutable references to the items of an [`ArrDeqApi`].
+pub struct IterMutDeq<'a, T> {
+    pub(crate) items: core::iter::Chain<core::slice::IterMut<'a, T>, core::slice::IterMut<'a, T>>,
+}
+impl<'a, T> Iterator for IterMutDeq<'a, T> {
+    type Item = &'a mut T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.items.next()
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.items.size_hint()
+    }
+}
+impl<T> DoubleEndedIterator for IterMutDeq<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.items.next_back()
+    }
+}
+impl<T> ExactSizeIterator for IterMutDeq<'_, T> {}
+
+impl<'a, A: Array> IntoIterator for &'a ArrDeqApi<A> {
+    type Item = &'a A::Item;
+    type IntoIter = IterDeq<'a, A::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let (lhs, rhs) = self.as_slices();
+        IterDeq {
+            items: lhs.iter().chain(rhs),
+        }
+    }
+}
+impl<'a, A: Array> IntoIterator for &'a mut ArrDeqApi<A> {
+    type Item = &'a mut A::Item;
+    type IntoIter = IterMutDeq<'a, A::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let (lhs, rhs) = self.as_mut_slices();
+        IterMutDeq {
+            items: lhs.iter_mut().chain(rhs),
+        }
+    }
+}
+
+impl<'a, A: Array> IntoIterator for &'a ArrVecApi<A> {
+    type Item = &'a A::Item;
+    type IntoIter = core::slice::Iter<'a, A::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_slice().iter()
+    }
+}
+impl<'a, A: Array> IntoIterator for &'a mut ArrVecApi<A> {
+    type Item = &'a mut A::Item;
+    type IntoIter = core::slice::IterMut<'a, A::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_mut_slice().iter_mut()
+    }
+}
@@ -0,0 +1,181 @@
+use core::iter::{Product, Sum};
+use core::ops::*;
+
+use crate::{Uint, array::*};
+
+// Element-wise operations between two arrays of the same length.
+macro_rules! arr_binops {
+    ($scalars:tt $($Op:ident $op:ident $OpAssign:ident $op_assign:ident,)*) => {$(
+        impl<A, B> $Op<ArrApi<B>> for ArrApi<A>
+        where
+            A: Array<Item: $Op<B::Item>>,
+            B: Array<Length = A::Length>,
+        {
+            type Output = Arr<<A::Item as $Op<B::Item>>::Output, A::Length>;
+            fn $op(self, rhs: ArrApi<B>) -> Self::Output {
+                self.zip(rhs).map(|(l, r)| l.$op(r))
+            }
+        }
+
+        impl<A, B> $OpAssign<ArrApi<B>> for ArrApi<A>
+        where
+            A: Array<Item: $OpAssign<B::Item>>,
+            B: Array<Length = A::Length>,
+        {
+            fn $op_assign(&mut self, rhs: ArrApi<B>) {
+                for (l, r) in self.iter_mut().zip(rhs) {
+                    l.$op_assign(r);
+                }
+            }
+        }
+
+        scalar_binops! { $Op $op $OpAssign $op_assign: $scalars }
+    )*};
+}
+
+// Operations between an array and a scalar, which is broadcast to every item. These can't
+// be implemented for generic scalars because they would overlap with the impls above.
+macro_rules! scalar_binops {
+    ($Op:ident $op:ident $OpAssign:ident $op_assign:ident: [$($S:ty)*]) => {$(
+        impl<A> $Op<$S> for ArrApi<A>
+        where
+            A: Array<Item: $Op<$S>>,
+        {
+            type Output = Arr<<A::Item as $Op<$S>>::Output, A::Length>;
+            fn $op(self, rhs: $S) -> Self::Output {
+                self.map(|l| l.$op(rhs))
+            }
+        }
+
+        impl<A> $Op<ArrApi<A>> for $S
+        where
+            A: Array,
+            $S: $Op<A::Item>,
+        {
+            type Output = Arr<<$S as $Op<A::Item>>::Output, A::Length>;
+            fn $op(self, rhs: ArrApi<A>) -> Self::Output {
+                rhs.map(|r| self.$op(r))
+            }
+        }
+
+        impl<A> $OpAssign<$S> for ArrApi<A>
+        where
+            A: Array<Item: $OpAssign<$S>>,
+        {
+            fn $op_assign(&mut self, rhs: $S) {
+                for l in self.iter_mut() {
+                    l.$op_assign(rhs);
+                }
+            }
+        }
+    )*};
+}
+
+arr_binops! {
+    [i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize f32 f64]
+    Add add AddAssign add_assign,
+    Sub sub SubAssign sub_assign,
+    Mul mul MulAssign mul_assign,
+    Div div DivAssign div_assign,
+    Rem rem RemAssign rem_assign,
+}
+arr_binops! {
+    [i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize bool]
+    BitAnd bitand BitAndAssign bitand_assign,
+    BitOr bitor BitOrAssign bitor_assign,
+    BitXor bitxor BitXorAssign bitxor_assign,
+}
+arr_binops! {
+    [i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize]
+    Shl shl ShlAssign shl_assign,
+    Shr shr ShrAssign shr_assign,
+}
+
+macro_rules! arr_unops {
+    ($($Op:ident $op:ident,)*) => {$(
+        impl<A> $Op for ArrApi<A>
+        where
+            A: Array<Item: $Op>,
+        {
+            type Output = Arr<<A::Item as $Op>::Output, A::Length>;
+            fn $op(self) -> Self::Output {
+                self.map(|x| x.$op())
+            }
+        }
+    )*};
+}
+
+arr_unops! {
+    Neg neg,
+    Not not,
+}
+
+impl<T, N: Uint, A> ArrApi<A>
+where
+    A: Array<Item = T, Length = N>,
+{
+    /// Sums the items of the array.
+    ///
+    /// Equivalent to `self.into_iter().sum()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::{array::*, small::*};
+    /// let arr = Arr::<_, U4>::from_fn(|i| i as f32);
+    /// assert_eq!(arr.sum(), 6.0);
+    /// ```
+    pub fn sum(self) -> T
+    where
+        T: Sum,
+    {
+        self.into_iter().sum()
+    }
+
+    /// Multiplies the items of the array.
+    ///
+    /// Equivalent to `self.into_iter().product()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::{array::*, small::*};
+    /// let arr = Arr::<_, U4>::from_fn(|i| i + 1);
+    /// assert_eq!(arr.product(), 24);
+    /// ```
+    pub fn product(self) -> T
+    where
+        T: Product,
+    {
+        self.into_iter().product()
+    }
+
+    /// Computes the dot product of two arrays of the same length.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::{array::*, small::*};
+    /// let a = CopyArr::<f32, U3>::from_fn(|i| i as f32);
+    /// let b = CopyArr::<f32, U3>::of(2.0);
+    /// assert_eq!(a.dot(b), 6.0);
+    ///
+    /// // Element-wise operators and scalar broadcasting
+    /// assert_eq!(a + b, [2.0, 3.0, 4.0]);
+    /// assert_eq!(a * 2.0 - b, [-2.0, 0.0, 2.0]);
+    /// assert_eq!(-(2.0 * a), [-0.0, -2.0, -4.0]);
+    /// let mut c = a;
+    /// c += b;
+    /// c /= 2.0;
+    /// assert_eq!(c, [1.0, 1.5, 2.0]);
+    ///
+    /// let mask = ArrApi::new([true, false, true]);
+    /// assert_eq!(mask & false, [false; 3]);
+    /// assert_eq!(!mask ^ true, mask);
+    /// ```
+    pub fn dot<B, O>(self, rhs: ArrApi<B>) -> O
+    where
+        B: Array<Length = N>,
+        T: Mul<B::Item, Output = O>,
+        O: Sum,
+    {
+        self.zip(rhs).into_iter().map(|(l, r)| l * r).sum()
+    }
+}
@@ -0,0 +1,217 @@
+use crate::{Uint, array::*, internals::PrimitiveOrdSealed};
+
+macro_rules! impl_primitive_ord {
+    ($signed:literal: $($T:ty)*) => {$(
+        impl PrimitiveOrdSealed for $T {
+            const SIGNED: bool = $signed;
+        }
+        impl PrimitiveOrd for $T {}
+    )*};
+}
+impl_primitive_ord!(false: u8 u16 u32 u64 u128 usize char);
+impl_primitive_ord!(true: i8 i16 i32 i64 i128 isize);
+
+/// Maps a primitive to a `u128` with the same ordering.
+const fn key<T: PrimitiveOrd>(x: &T) -> u128 {
+    const SIGN: u128 = 1 << 127;
+    let ptr: *const T = x;
+    // SAFETY: `T` is a primitive integer or `char` (which is a valid `u32`), so it is valid
+    // to read as the integer type of the same size and signedness.
+    unsafe {
+        match (size_of::<T>(), T::SIGNED) {
+            (1, false) => ptr.cast::<u8>().read() as _,
+            (2, false) => ptr.cast::<u16>().read() as _,
+            (4, false) => ptr.cast::<u32>().read() as _,
+            (8, false) => ptr.cast::<u64>().read() as _,
+            (16, false) => ptr.cast::<u128>().read(),
+            (1, true) => ptr.cast::<i8>().read() as i128 as u128 ^ SIGN,
+            (2, true) => ptr.cast::<i16>().read() as i128 as u128 ^ SIGN,
+            (4, true) => ptr.cast::<i32>().read() as i128 as u128 ^ SIGN,
+            (8, true) => ptr.cast::<i64>().read() as i128 as u128 ^ SIGN,
+            (16, true) => ptr.cast::<i128>().read() as u128 ^ SIGN,
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl<T: PrimitiveOrd, N: Uint, A> ArrApi<A>
+where
+    A: Array<Item = T, Length = N>,
+{
+    /// Equivalent of [`<[T]>::sort_unstable`](slice::sort_unstable), but `const`.
+    ///
+    /// The array is sorted using a sorting network (Batcher's merge exchange), so the
+    /// sequence of comparisons only depends on the length of the array. It performs
+    /// `O(n log² n)` comparisons.
+    ///
+    /// # Panics
+    /// If `Length > usize::MAX`.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::array::*;
+    /// const SORTED: [i32; 6] = {
+    ///     let mut arr = ArrApi::new([3, -1, 4, 1, -5, 9]);
+    ///     arr.sort_unstable();
+    ///     arr.into_inner()
+    /// };
+    /// assert_eq!(SORTED, [-5, -1, 1, 3, 4, 9]);
+    /// ```
+    ///
+    /// The network sorts every input:
+    /// ```
+    /// use genuint::{array::*, small::*, Uint};
+    /// fn check_all<N: Uint>() {
+    ///     let len = Arr::<u8, N>::length() as u32;
+    ///     for seed in 0..4usize.pow(len) {
+    ///         let mut arr = Arr::<u8, N>::from_fn(|i| (seed / 4usize.pow(i as u32) % 4) as u8);
+    ///         let mut expected = arr.clone();
+    ///         expected.as_mut_slice().sort();
+    ///         arr.sort_unstable();
+    ///         assert_eq!(arr, expected);
+    ///     }
+    /// }
+    /// check_all::<U0>();
+    /// check_all::<U1>();
+    /// check_all::<U2>();
+    /// check_all::<U3>();
+    /// check_all::<U5>();
+    /// check_all::<U6>();
+    /// check_all::<U7>();
+    /// ```
+    #[track_caller]
+    pub const fn sort_unstable(&mut self) {
+        let slice = self.as_mut_slice();
+        let n = slice.len();
+        if n < 2 {
+            return;
+        }
+
+        // Knuth, TAOCP Vol. 3, 5.2.2, Algorithm M
+        let t = usize::BITS - (n - 1).leading_zeros();
+        let mut p = 1 << (t - 1);
+        while p > 0 {
+            let mut q = 1 << (t - 1);
+            let mut r = 0;
+            let mut d = p;
+            loop {
+                let mut i = 0;
+                while i < n - d {
+                    if i & p == r && key(&slice[i]) > key(&slice[i + d]) {
+                        slice.swap(i, i + d);
+                    }
+                    i += 1;
+                }
+                if q == p {
+                    break;
+                }
+                d = q - p;
+                q >>= 1;
+                r = p;
+            }
+            p >>= 1;
+        }
+    }
+
+    /// Equivalent of [`<[T]>::is_sorted`](slice::is_sorted), but `const`.
+    ///
+    /// # Panics
+    /// If `Length > usize::MAX`.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::array::*;
+    /// assert!(ArrApi::new(['a', 'b', 'b']).is_sorted());
+    /// assert!(!ArrApi::new([2, 1]).is_sorted());
+    /// ```
+    #[track_caller]
+    pub const fn is_sorted(&self) -> bool {
+        let mut slice = self.as_slice();
+        while let [first, second, ..] = slice {
+            if key(first) > key(second) {
+                return false;
+            }
+            slice = slice.split_at(1).1;
+        }
+        true
+    }
+
+    /// Equivalent of [`<[T]>::binary_search`](slice::binary_search), but `const`.
+    ///
+    /// # Errors
+    /// If `x` is not found, returns the index where it could be inserted while keeping the
+    /// array sorted.
+    ///
+    /// # Panics
+    /// If `Length > usize::MAX`.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::array::*;
+    /// const TABLE: [u16; 5] = [1, 3, 7, 15, 31];
+    /// const IDX: Result<usize, usize> = ArrApi::new(TABLE).binary_search(&7);
+    /// assert_eq!(IDX, Ok(2));
+    /// assert_eq!(ArrApi::new(TABLE).binary_search(&8), Err(3));
+    /// ```
+    #[track_caller]
+    pub const fn binary_search(&self, x: &T) -> Result<usize, usize> {
+        let slice = self.as_slice();
+        let x = key(x);
+        let mut lo = 0;
+        let mut hi = slice.len();
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            let item = key(&slice[mid]);
+            if item == x {
+                return Ok(mid);
+            } else if item < x {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        Err(lo)
+    }
+
+    /// Equivalent of [`<[T]>::contains`](slice::contains), but `const`.
+    ///
+    /// # Panics
+    /// If `Length > usize::MAX`.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::array::*;
+    /// const HAS_X: bool = ArrApi::new(['x', 'y']).contains(&'x');
+    /// assert!(HAS_X);
+    /// ```
+    #[track_caller]
+    pub const fn contains(&self, x: &T) -> bool {
+        self.position(x).is_some()
+    }
+
+    /// Returns the index of the first item equal to `x`, or `None` if there is none.
+    ///
+    /// # Panics
+    /// If `Length > usize::MAX`.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::array::*;
+    /// let arr = ArrApi::new([5, 6, 5]);
+    /// assert_eq!(arr.position(&5), Some(0));
+    /// assert_eq!(arr.position(&7), None);
+    /// ```
+    #[track_caller]
+    pub const fn position(&self, x: &T) -> Option<usize> {
+        let slice = self.as_slice();
+        let x = key(x);
+        let mut i = 0;
+        while i < slice.len() {
+            if key(&slice[i]) == x {
+                return Some(i);
+            }
+            i += 1;
+        }
+        None
+    }
+}
@@ -0,0 +1,188 @@
+use std::io;
+
+use crate::array::*;
+
+fn write_zero() -> io::Error {
+    io::Error::from(io::ErrorKind::WriteZero)
+}
+
+/// Writes into the spare capacity of the vector.
+///
+/// If the vector is full, writing a nonempty buffer returns an error of kind
+/// [`WriteZero`](io::ErrorKind::WriteZero). Otherwise, as many bytes as fit are written.
+///
+/// # Examples
+/// ```
+/// use genuint::{array::*, small::*};
+/// use std::io::{ErrorKind, Write};
+///
+/// let mut frame = ArrVec::<u8, U8>::new();
+/// write!(frame, "{}:{}", 12, "ab").unwrap();
+/// assert_eq!(frame, *b"12:ab");
+/// assert_eq!(frame.write(b"cdef").unwrap(), 3);
+/// assert_eq!(frame.write(b"g").unwrap_err().kind(), ErrorKind::WriteZero);
+/// ```
+impl<A: Array<Item = u8>> io::Write for ArrVecApi<A> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.is_full() && !buf.is_empty() {
+            return Err(write_zero());
+        }
+        let n = buf.len().min(self.spare_len());
+        self.extend_from_slice(&buf[..n]);
+        Ok(n)
+    }
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Reads bytes from the front of the deque, removing them.
+///
+/// # Examples
+/// ```
+/// use genuint::{array::*, small::*};
+/// use std::io::{BufRead, Read, Write};
+///
+/// let mut buf = ArrDeq::<u8, U8>::new();
+/// buf.write_all(b"\x03abc\x01").unwrap();
+/// let mut len = [0];
+/// buf.read_exact(&mut len).unwrap();
+/// let mut frame = [0; 3];
+/// buf.read_exact(&mut frame).unwrap();
+/// assert_eq!(&frame, b"abc");
+///
+/// // The freed capacity can be written again, even though it wraps around
+/// buf.write_all(b"x\nrest").unwrap();
+/// let mut line = String::new();
+/// buf.read_line(&mut line).unwrap();
+/// assert_eq!(line, "\x01x\n");
+/// assert_eq!(buf, *b"rest");
+/// ```
+impl<A: Array<Item = u8>> io::Read for ArrDeqApi<A> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let (mut front, mut back) = self.as_slices();
+        let mut n = front.read(buf)?;
+        n += back.read(&mut buf[n..])?;
+        self.drain(..n);
+        Ok(n)
+    }
+}
+impl<A: Array<Item = u8>> io::BufRead for ArrDeqApi<A> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        Ok(self.as_slices().0)
+    }
+    fn consume(&mut self, amt: usize) {
+        self.drain(..amt);
+    }
+}
+/// Writes to the back of the deque.
+///
+/// If the deque is full, writing a nonempty buffer returns an error of kind
+/// [`WriteZero`](io::ErrorKind::WriteZero). Otherwise, as many bytes as fit are written.
+impl<A: Array<Item = u8>> io::Write for ArrDeqApi<A> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.is_full() && !buf.is_empty() {
+            return Err(write_zero());
+        }
+        let n = buf.len().min(self.capacity() - self.len());
+        buf[..n].iter().for_each(|&byte| self.push_back(byte));
+        Ok(n)
+    }
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<A: Array<Item = u8>> ArrCursor<A> {
+    /// Creates a cursor at the start of `arr`.
+    pub const fn new(arr: A) -> Self {
+        Self {
+            arr: ArrApi::new(arr),
+            pos: 0,
+        }
+    }
+
+    /// Returns the underlying array.
+    pub fn into_inner(self) -> ArrApi<A> {
+        self.arr
+    }
+
+    /// Returns a reference to the underlying array.
+    pub const fn get_ref(&self) -> &ArrApi<A> {
+        &self.arr
+    }
+
+    /// Returns a mutable reference to the underlying array.
+    pub const fn get_mut(&mut self) -> &mut ArrApi<A> {
+        &mut self.arr
+    }
+
+    /// Returns the current position of the cursor.
+    pub const fn position(&self) -> u64 {
+        self.pos
+    }
+
+    /// Sets the position of the cursor. It may lie past the end of the array.
+    pub const fn set_position(&mut self, pos: u64) {
+        self.pos = pos;
+    }
+
+    /// Returns the offset of the position into the array, capped to its length.
+    fn offset(&self) -> usize {
+        let len = self.arr.as_slice().len();
+        usize::try_from(self.pos).map_or(len, |pos| pos.min(len))
+    }
+}
+
+impl<A: Array<Item = u8>> io::Read for ArrCursor<A> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = io::BufRead::fill_buf(self)?.read(buf)?;
+        io::BufRead::consume(self, n);
+        Ok(n)
+    }
+}
+impl<A: Array<Item = u8>> io::BufRead for ArrCursor<A> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        let offset = self.offset();
+        Ok(&self.arr.as_slice()[offset..])
+    }
+    fn consume(&mut self, amt: usize) {
+        self.pos += amt as u64;
+    }
+}
+impl<A: Array<Item = u8>> io::Write for ArrCursor<A> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let offset = self.offset();
+        let n = (&mut self.arr.as_mut_slice()[offset..]).write(buf)?;
+        self.pos += n as u64;
+        Ok(n)
+    }
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+impl<A: Array<Item = u8>> io::Seek for ArrCursor<A> {
+    fn seek(&mut self, style: io::SeekFrom) -> io::Result<u64> {
+        let (base, offset) = match style {
+            io::SeekFrom::Start(pos) => {
+                self.pos = pos;
+                return Ok(pos);
+            }
+            io::SeekFrom::End(offset) => (self.arr.as_slice().len() as u64, offset),
+            io::SeekFrom::Current(offset) => (self.pos, offset),
+        };
+        match base.checked_add_signed(offset) {
+            Some(pos) => {
+                self.pos = pos;
+                Ok(pos)
+            }
+            None => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )),
+        }
+    }
+    fn stream_position(&mut self) -> io::Result<u64> {
+        Ok(self.pos)
+    }
+}
@@ -0,0 +1,291 @@
+//! Matrices with dimensions measured as [`Uint`]s.
+//!
+//! The main type of this module is [`Matrix`]. Its dimensions are part of its type, so
+//! multiplying matrices with mismatching dimensions is a compile error.
+//!
+//! # Examples
+//! ```
+//! use genuint::{array::{*, matrix::Matrix}, small::*};
+//! let a = Matrix::<i32, U2, U3>::from_fn(|r, c| (r * 3 + c) as i32);
+//! let b = a.clone().transpose();
+//! assert_eq!(b.row(2), &[2, 5]);
+//!
+//! let product = a * b;
+//! assert_eq!(product, Matrix::from_flat(ArrApi::new([5, 14, 14, 50]).retype()));
+//! assert_eq!(product.clone() * Matrix::identity(), product);
+//! ```
+//!
+//! The inner dimensions of a product have to match.
+//! ```compile_fail
+//! use genuint::{array::matrix::Matrix, small::*};
+//! let a = Matrix::<i32, U2, U3>::from_fn(|r, c| (r * 3 + c) as i32);
+//! let _ = a.clone() * a;
+//! ```
+
+use core::iter::Sum;
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
+
+use crate::{
+    Uint,
+    array::{Arr, ArrApi, ArrFlatten},
+    uint, uops,
+};
+
+/// A matrix with `R` rows and `C` columns, stored in row-major order.
+///
+/// Dimensions exceeding [`usize::MAX`] are not supported.
+///
+/// Besides matrix multiplication, the following operations are supported:
+/// - Multiplication with a column vector of type [`Arr<T, C>`].
+/// - Element-wise addition, subtraction and negation.
+/// - Multiplication and division by a scalar of type `T`.
+#[repr(transparent)]
+pub struct Matrix<T, R: Uint, C: Uint> {
+    /// The rows of the matrix.
+    pub rows: Arr<Arr<T, C>, R>,
+}
+
+/// The flattened form of a [`Matrix`].
+pub type Flat<T, R, C> = Arr<T, uint::From<uops::Mul<R, C>>>;
+
+impl<T, R: Uint, C: Uint> Matrix<T, R, C> {
+    /// Creates a matrix from its rows.
+    pub const fn new(rows: Arr<Arr<T, C>, R>) -> Self {
+        Self { rows }
+    }
+
+    /// Returns the rows of the matrix.
+    pub const fn into_rows(self) -> Arr<Arr<T, C>, R> {
+        // SAFETY: repr(transparent)
+        unsafe {
+            crate::utils::union_transmute!(
+                Matrix::<T, R, C>, //
+                Arr::<Arr<T, C>, R>,
+                self,
+            )
+        }
+    }
+
+    /// Creates a matrix by calling `f` with the row and column of each item.
+    ///
+    /// The items are created in row-major order.
+    ///
+    /// # Panics
+    /// If `R > usize::MAX` or `C > usize::MAX`.
+    #[track_caller]
+    pub fn from_fn(mut f: impl FnMut(usize, usize) -> T) -> Self {
+        Self::new(ArrApi::from_fn(|r| ArrApi::from_fn(|c| f(r, c))))
+    }
+
+    /// Creates a matrix from its items in row-major order.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::{array::{*, matrix::Matrix}, small::*};
+    /// let m = Matrix::<_, U2, U2>::from_flat(ArrApi::new([1, 2, 3, 4]).retype());
+    /// assert_eq!(m.row(1), &[3, 4]);
+    /// assert_eq!(m.into_flat(), [1, 2, 3, 4]);
+    /// ```
+    pub const fn from_flat(flat: Flat<T, R, C>) -> Self {
+        Self::new(flat.retype::<ArrFlatten<_>>().into_inner())
+    }
+
+    /// Returns the items of the matrix in row-major order.
+    pub const fn into_flat(self) -> Flat<T, R, C> {
+        self.into_rows().flatten().retype()
+    }
+
+    /// Returns a reference to the row at index `r`.
+    ///
+    /// # Panics
+    /// If `r >= R`.
+    pub const fn row(&self, r: usize) -> &Arr<T, C> {
+        &self.rows.as_slice()[r]
+    }
+
+    /// Returns a mutable reference to the row at index `r`.
+    ///
+    /// # Panics
+    /// If `r >= R`.
+    pub const fn row_mut(&mut self, r: usize) -> &mut Arr<T, C> {
+        &mut self.rows.as_mut_slice()[r]
+    }
+
+    /// Returns references to the items of the column at index `c`.
+    ///
+    /// # Panics
+    /// If `c >= C`.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::{array::matrix::Matrix, small::*};
+    /// let m = Matrix::<_, U3, U2>::from_fn(|r, c| r * 2 + c);
+    /// assert_eq!(m.col(1), [&1, &3, &5]);
+    /// ```
+    #[track_caller]
+    pub fn col(&self, c: usize) -> Arr<&T, R> {
+        self.rows.each_ref().map(|row| &row[c])
+    }
+
+    /// Swaps the rows and columns of the matrix.
+    ///
+    /// # Panics
+    /// If `R > usize::MAX` or `C > usize::MAX`.
+    #[track_caller]
+    pub const fn transpose(self) -> Matrix<T, C, R> {
+        Matrix::new(self.into_rows().transpose())
+    }
+
+    /// Multiplies the matrices element-wise.
+    ///
+    /// Use `*` for matrix multiplication.
+    pub fn hadamard(self, rhs: Self) -> Self
+    where
+        T: Mul<Output = T>,
+    {
+        Self::new(self.rows.zip(rhs.rows).map(|(l, r)| l * r))
+    }
+}
+
+impl<T: From<bool>, N: Uint> Matrix<T, N, N> {
+    /// Creates the identity matrix, with `T::from(true)` on the diagonal and `T::from(false)`
+    /// everywhere else.
+    ///
+    /// # Panics
+    /// If `N > usize::MAX`.
+    ///
+    /// # Examples
+    /// ```
+    /// use genuint::{array::{*, matrix::Matrix}, small::*};
+    /// let m = Matrix::<f32, U2, U2>::identity();
+    /// assert_eq!(m.into_flat(), [1.0, 0.0, 0.0, 1.0]);
+    /// ```
+    #[track_caller]
+    pub fn identity() -> Self {
+        Self::from_fn(|r, c| T::from(r == c))
+    }
+}
+
+impl<T, R: Uint, C: Uint> Index<(usize, usize)> for Matrix<T, R, C> {
+    type Output = T;
+    fn index(&self, (r, c): (usize, usize)) -> &T {
+        &self.row(r)[c]
+    }
+}
+impl<T, R: Uint, C: Uint> IndexMut<(usize, usize)> for Matrix<T, R, C> {
+    fn index_mut(&mut self, (r, c): (usize, usize)) -> &mut T {
+        &mut self.row_mut(r)[c]
+    }
+}
+
+impl<T, R: Uint, K: Uint, C: Uint> Mul<Matrix<T, K, C>> for Matrix<T, R, K>
+where
+    T: Clone + Mul<Output = T> + Sum,
+{
+    type Output = Matrix<T, R, C>;
+    fn mul(self, rhs: Matrix<T, K, C>) -> Self::Output {
+        Matrix::from_fn(|r, c| {
+            let row = self.row(r).iter();
+            row.zip(rhs.col(c))
+                .map(|(l, r)| l.clone() * r.clone())
+                .sum()
+        })
+    }
+}
+
+impl<T, R: Uint, C: Uint> Mul<Arr<T, C>> for Matrix<T, R, C>
+where
+    T: Clone + Mul<Output = T> + Sum,
+{
+    type Output = Arr<T, R>;
+    fn mul(self, rhs: Arr<T, C>) -> Self::Output {
+        self.rows.map(|row| {
+            let row = row.into_iter();
+            row.zip(rhs.iter()).map(|(l, r)| l * r.clone()).sum()
+        })
+    }
+}
+
+impl<T: Add<Output = T>, R: Uint, C: Uint> Add for Matrix<T, R, C> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.rows + rhs.rows)
+    }
+}
+impl<T: AddAssign, R: Uint, C: Uint> AddAssign for Matrix<T, R, C> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.rows += rhs.rows;
+    }
+}
+impl<T: Sub<Output = T>, R: Uint, C: Uint> Sub for Matrix<T, R, C> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.rows - rhs.rows)
+    }
+}
+impl<T: SubAssign, R: Uint, C: Uint> SubAssign for Matrix<T, R, C> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.rows -= rhs.rows;
+    }
+}
+impl<T: Neg<Output = T>, R: Uint, C: Uint> Neg for Matrix<T, R, C> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::new(-self.rows)
+    }
+}
+impl<T: Clone + Mul<Output = T>, R: Uint, C: Uint> Mul<T> for Matrix<T, R, C> {
+    type Output = Self;
+    fn mul(self, rhs: T) -> Self {
+        Self::new(self.rows.map(|row| row.map(|l| l * rhs.clone())))
+    }
+}
+impl<T: Clone + MulAssign, R: Uint, C: Uint> MulAssign<T> for Matrix<T, R, C> {
+    fn mul_assign(&mut self, rhs: T) {
+        for item in self.rows.iter_mut().flat_map(|row| row.iter_mut()) {
+            *item *= rhs.clone();
+        }
+    }
+}
+impl<T: Clone + Div<Output = T>, R: Uint, C: Uint> Div<T> for Matrix<T, R, C> {
+    type Output = Self;
+    fn div(self, rhs: T) -> Self {
+        Self::new(self.rows.map(|row| row.map(|l| l / rhs.clone())))
+    }
+}
+impl<T: Clone + DivAssign, R: Uint, C: Uint> DivAssign<T> for Matrix<T, R, C> {
+    fn div_assign(&mut self, rhs: T) {
+        for item in self.rows.iter_mut().flat_map(|row| row.iter_mut()) {
+            *item /= rhs.clone();
+        }
+    }
+}
+
+impl<T: Clone, R: Uint, C: Uint> Clone for Matrix<T, R, C> {
+    fn clone(&self) -> Self {
+        Self::new(self.rows.clone())
+    }
+}
+impl<T: Default, R: Uint, C: Uint> Default for Matrix<T, R, C> {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+impl<T: core::fmt::Debug, R: Uint, C: Uint> core::fmt::Debug for Matrix<T, R, C> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Matrix").field(&self.rows).finish()
+    }
+}
+impl<T: PartialEq, R: Uint, C: Uint> PartialEq for Matrix<T, R, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.rows == other.rows
+    }
+}
+impl<T: Eq, R: Uint, C: Uint> Eq for Matrix<T, R, C> {}
+impl<T: core::hash::Hash, R: Uint, C: Uint> core::hash::Hash for Matrix<T, R, C> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.rows.hash(state);
+    }
+}
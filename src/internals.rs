@@ -22,6 +22,10 @@ pub(crate) use InternalOp;
 
 pub trait ArraySealed {}
 
+pub trait PrimitiveOrdSealed: Copy + Ord {
+    const SIGNED: bool;
+}
+
 // Map the internal API to the public one using an
 // undocumented associated type.
 pub trait UintSealed: 'static {
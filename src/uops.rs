@@ -239,7 +239,6 @@ macro_rules! opaque {
         }
     };
 }
-pub(crate) use opaque;
 
 macro_rules! test_op {
     (
@@ -254,7 +253,6 @@ macro_rules! test_op {
         $v $kw $TypeName<$($P $(= $Def)?),*> $($rest)*
     };
 }
-pub(crate) use test_op;
 
 macro_rules! base_case {
     (
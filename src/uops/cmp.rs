@@ -101,15 +101,17 @@ pub type _Le<L, R> = IsZero<_Lt<R, L>>;
 pub type Le<L, R> = _Le;
 
 #[apply(lazy)]
-pub type _Min<L, R> = If<_Lt<L, R>, R, L>;
+pub type _Min<L, R> = If<_Lt<L, R>, L, R>;
 
 /// Type-level [`min`](core::cmp::min)
 #[apply(opaque)]
+#[apply(test_op! test_min, L.min(R))]
 pub type Min<L, R> = _Min;
 
 #[apply(lazy)]
-pub type _Max<L, R> = If<_Lt<L, R>, L, R>;
+pub type _Max<L, R> = If<_Lt<L, R>, R, L>;
 
 /// Type-level [`max`](core::cmp::max)
 #[apply(opaque)]
+#[apply(test_op! test_max, L.max(R))]
 pub type Max<L, R> = _Max;